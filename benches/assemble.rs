@@ -0,0 +1,51 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+use mipssembler::assemble;
+
+const BLOCK_COUNTS: [usize; 2] = [1000, 20000];
+
+fn compose_synthetic_program(block_count: usize) -> String {
+    let mut program = String::from("\t.data\n");
+    for i in 0..block_count {
+        program.push_str(&format!("data{}:\t.word\t{}\n", i, i));
+    }
+
+    program.push_str("\t.text\nmain:\n");
+    for i in 0..block_count {
+        program.push_str(&format!(
+            "loop{i}:\n\
+             \tla\t$8, data{i}\n\
+             \tlw\t$9, 0($8)\n\
+             \taddiu\t$9, $9, 0x1\n\
+             \tsll\t$10, $9, 2\n\
+             \tor\t$11, $10, $9\n\
+             \tsw\t$11, 0($8)\n\
+             \tbne\t$9, $11, loop{i}\n\
+             \tj\tmain\n",
+            i = i
+        ));
+    }
+
+    program
+}
+
+fn bench_assemble(c: &mut Criterion) {
+    let mut group = c.benchmark_group("assemble synthetic program");
+    group.sample_size(10);
+    BLOCK_COUNTS.iter().for_each(|block_count| {
+        let mut input_file = NamedTempFile::new().unwrap();
+        input_file
+            .write_all(compose_synthetic_program(*block_count).as_bytes())
+            .unwrap();
+
+        group.bench_function(BenchmarkId::from_parameter(block_count), |b| {
+            b.iter(|| assemble(input_file.as_file_mut(), false, false))
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_assemble);
+criterion_main!(benches);
@@ -23,10 +23,79 @@ pub const INSTRUCTION_TABLE: Map<&'static str, &'static Instruction> = phf_map!
  "sll" => &Instruction::new("sll", 0, 0x0),
  "srl" => &Instruction::new("srl", 0, 0x2),
  "sw" => &Instruction::new("sw", 0x2b, -1),
+ "syscall" => &Instruction::new("syscall", 0, 0xc),
  "subu" => &Instruction::new("subu", 0, 0x23),
+ "add" => &Instruction::new("add", 0, 0x20),
+ "addi" => &Instruction::new("addi", 0x8, -1),
+ "sub" => &Instruction::new("sub", 0, 0x22),
+ "slt" => &Instruction::new("slt", 0, 0x2a),
+ "slti" => &Instruction::new("slti", 0xa, -1),
+ "xor" => &Instruction::new("xor", 0, 0x26),
+ "xori" => &Instruction::new("xori", 0xe, -1),
+ "sra" => &Instruction::new("sra", 0, 0x3),
+ "sllv" => &Instruction::new("sllv", 0, 0x4),
+ "srlv" => &Instruction::new("srlv", 0, 0x6),
+ "srav" => &Instruction::new("srav", 0, 0x7),
+ "jalr" => &Instruction::new("jalr", 0, 0x9),
+ "break" => &Instruction::new("break", 0, 0xd),
+ "mfhi" => &Instruction::new("mfhi", 0, 0x10),
+ "mthi" => &Instruction::new("mthi", 0, 0x11),
+ "mflo" => &Instruction::new("mflo", 0, 0x12),
+ "mtlo" => &Instruction::new("mtlo", 0, 0x13),
+ "mult" => &Instruction::new("mult", 0, 0x18),
+ "multu" => &Instruction::new("multu", 0, 0x19),
+ "div" => &Instruction::new("div", 0, 0x1a),
+ "divu" => &Instruction::new("divu", 0, 0x1b),
+ "blez" => &Instruction::new("blez", 0x6, -1),
+ "bgtz" => &Instruction::new("bgtz", 0x7, -1),
+ "lb" => &Instruction::new("lb", 0x20, -1),
+ "lh" => &Instruction::new("lh", 0x21, -1),
+ "lbu" => &Instruction::new("lbu", 0x24, -1),
+ "lhu" => &Instruction::new("lhu", 0x25, -1),
+ "sb" => &Instruction::new("sb", 0x28, -1),
+ "sh" => &Instruction::new("sh", 0x29, -1),
+ "mfc0" => &Instruction::new_coprocessor("mfc0", 0x10, 0x0, 0x0),
+ "mtc0" => &Instruction::new_coprocessor("mtc0", 0x10, 0x4, 0x0),
+ "eret" => &Instruction::new_coprocessor("eret", 0x10, 0x10, 0x18),
+ "add.s" => &Instruction::new_coprocessor("add.s", 0x11, 0x10, 0x0),
+ "sub.s" => &Instruction::new_coprocessor("sub.s", 0x11, 0x10, 0x1),
+ "mul.s" => &Instruction::new_coprocessor("mul.s", 0x11, 0x10, 0x2),
+ "div.s" => &Instruction::new_coprocessor("div.s", 0x11, 0x10, 0x3),
+ "abs.s" => &Instruction::new_coprocessor("abs.s", 0x11, 0x10, 0x5),
+ "mov.s" => &Instruction::new_coprocessor("mov.s", 0x11, 0x10, 0x6),
+ "neg.s" => &Instruction::new_coprocessor("neg.s", 0x11, 0x10, 0x7),
+ "add.d" => &Instruction::new_coprocessor("add.d", 0x11, 0x11, 0x0),
+ "sub.d" => &Instruction::new_coprocessor("sub.d", 0x11, 0x11, 0x1),
+ "mul.d" => &Instruction::new_coprocessor("mul.d", 0x11, 0x11, 0x2),
+ "div.d" => &Instruction::new_coprocessor("div.d", 0x11, 0x11, 0x3),
+ "abs.d" => &Instruction::new_coprocessor("abs.d", 0x11, 0x11, 0x5),
+ "mov.d" => &Instruction::new_coprocessor("mov.d", 0x11, 0x11, 0x6),
+ "neg.d" => &Instruction::new_coprocessor("neg.d", 0x11, 0x11, 0x7),
+ "cvt.s.d" => &Instruction::new_coprocessor("cvt.s.d", 0x11, 0x11, 0x20),
+ "cvt.s.w" => &Instruction::new_coprocessor("cvt.s.w", 0x11, 0x14, 0x20),
+ "cvt.d.s" => &Instruction::new_coprocessor("cvt.d.s", 0x11, 0x10, 0x21),
+ "cvt.d.w" => &Instruction::new_coprocessor("cvt.d.w", 0x11, 0x14, 0x21),
+ "cvt.w.s" => &Instruction::new_coprocessor("cvt.w.s", 0x11, 0x10, 0x24),
+ "cvt.w.d" => &Instruction::new_coprocessor("cvt.w.d", 0x11, 0x11, 0x24),
+ "c.eq.s" => &Instruction::new_coprocessor("c.eq.s", 0x11, 0x10, 0x32),
+ "c.lt.s" => &Instruction::new_coprocessor("c.lt.s", 0x11, 0x10, 0x3c),
+ "c.le.s" => &Instruction::new_coprocessor("c.le.s", 0x11, 0x10, 0x3e),
+ "c.eq.d" => &Instruction::new_coprocessor("c.eq.d", 0x11, 0x11, 0x32),
+ "c.lt.d" => &Instruction::new_coprocessor("c.lt.d", 0x11, 0x11, 0x3c),
+ "c.le.d" => &Instruction::new_coprocessor("c.le.d", 0x11, 0x11, 0x3e),
+ "mfc1" => &Instruction::new_coprocessor("mfc1", 0x11, 0x0, 0x0),
+ "mtc1" => &Instruction::new_coprocessor("mtc1", 0x11, 0x4, 0x0),
+ "bc1f" => &Instruction::new_coprocessor("bc1f", 0x11, 0x8, 0x0),
+ "bc1t" => &Instruction::new_coprocessor("bc1t", 0x11, 0x8, 0x1),
+ "lwc1" => &Instruction::new("lwc1", 0x31, -1),
+ "ldc1" => &Instruction::new("ldc1", 0x35, -1),
+ "swc1" => &Instruction::new("swc1", 0x39, -1),
+ "sdc1" => &Instruction::new("sdc1", 0x3d, -1),
 };
 
 pub const WORD: i32 = 4;
 
 pub const DATA_SECTION_MIN_ADDRESS: i32 = 0x10000000;
 pub const TEXT_SECTION_MIN_ADDRESS: i32 = 0x400000;
+pub const KERNEL_DATA_SECTION_MIN_ADDRESS: i32 = 0x9000_0000_u32 as i32;
+pub const KERNEL_TEXT_SECTION_MIN_ADDRESS: i32 = 0x8000_0180_u32 as i32;
@@ -1,11 +1,21 @@
+use crate::constants::{KERNEL_DATA_SECTION_MIN_ADDRESS, WORD};
+use crate::endianness::Endianness;
+use crate::label::{find_label, Label};
 use crate::line::Line;
 use crate::section::Section;
+use crate::source::Source;
 use crate::utils::{convert_int_to_binary, convert_string_to_int};
 
+const DOUBLE_WORD: i32 = 8;
+
 pub struct Datum {
     pub name: String,
     value: i32,
+    big_endian_value: Option<i32>,
     pub address: i32,
+    pub is_named: bool,
+    pub symbol: Option<String>,
+    pub source: Option<Source>,
 }
 
 impl Datum {
@@ -13,12 +23,31 @@ impl Datum {
         Self {
             name: name.to_string(),
             value,
+            big_endian_value: None,
             address,
+            is_named: true,
+            symbol: None,
+            source: None,
         }
     }
 
-    pub fn to_binary(&self) -> String {
-        convert_int_to_binary(self.value, 32)
+    pub fn is_kernel(&self) -> bool {
+        self.address as u32 >= KERNEL_DATA_SECTION_MIN_ADDRESS as u32
+    }
+
+    pub fn to_binary(&self, endianness: Endianness) -> String {
+        convert_int_to_binary(self.to_word(endianness) as i32, 32)
+    }
+
+    pub fn to_word(&self, endianness: Endianness) -> u32 {
+        match (endianness, self.big_endian_value) {
+            (Endianness::BIG, Some(value)) => value as u32,
+            _ => self.value as u32,
+        }
+    }
+
+    pub fn to_bytes(&self, endianness: Endianness) -> Vec<u8> {
+        endianness.word_to_bytes(self.to_word(endianness)).to_vec()
     }
 }
 
@@ -27,40 +56,117 @@ pub fn extract_data_from_lines(lines: &[Line]) -> Vec<Datum> {
 
     lines
         .iter()
-        .filter(|line| line.section == Section::DATA)
-        .map(|line| {
-            if let Some(datum) =
-                resolve_data(&line.text.as_ref().unwrap(), &prev_datum_name, line.address)
-            {
+        .filter(|line| line.section == Section::DATA || line.section == Section::KDATA)
+        .flat_map(|line| {
+            let text = line.text.as_ref().unwrap();
+            let data = resolve_data(text, &prev_datum_name, line.address);
+            if let Some(datum) = data.last() {
                 prev_datum_name = Some(datum.name.clone());
-                Some(datum)
-            } else {
-                None
             }
+            data.into_iter().map(move |datum| Datum {
+                source: Some(Source::new(line.number, text, text)),
+                ..datum
+            })
         })
-        .filter_map(|line| line)
         .collect()
 }
 
+pub fn resolve_data_symbols(data: &mut [Datum], labels: &[Label], externs: &[String]) {
+    let values = data
+        .iter()
+        .map(|datum| {
+            datum.symbol.as_ref().map(|symbol| {
+                if let Some(datum) = find_datum(symbol, data) {
+                    datum.address
+                } else if let Some(label) = find_label(symbol, labels) {
+                    label.address
+                } else if externs.contains(symbol) {
+                    0
+                } else {
+                    panic!("Use of undeclared symbol.");
+                }
+            })
+        })
+        .collect::<Vec<Option<i32>>>();
+
+    data.iter_mut()
+        .zip(values)
+        .for_each(|(datum, value)| datum.value = value.unwrap_or(datum.value));
+}
+
 pub fn find_datum<'a>(name: &'a str, data: &'a [Datum]) -> Option<&'a Datum> {
     data.iter().find(|datum| datum.name == name)
 }
 
-fn resolve_data(code: &str, prev_datum_name: &Option<String>, address: i32) -> Option<Datum> {
-    if let [name, _, value] = code.split('\t').collect::<Vec<&str>>()[..] {
-        let value = convert_string_to_int(value);
+pub fn get_data_size(code: &str, address: i32) -> i32 {
+    match code.split('\t').nth(1) {
+        Some(".double") if address % DOUBLE_WORD != 0 => DOUBLE_WORD + WORD,
+        Some(".double") => DOUBLE_WORD,
+        _ => WORD,
+    }
+}
+
+fn resolve_values(directive: &str, value: &str) -> Vec<i32> {
+    match directive {
+        ".float" => vec![value.parse::<f32>().expect("Invalid float.").to_bits() as i32],
+        ".double" => {
+            let bits = value.parse::<f64>().expect("Invalid double.").to_bits();
+            vec![bits as u32 as i32, (bits >> 32) as u32 as i32]
+        }
+        _ => vec![convert_string_to_int(value)],
+    }
+}
+
+fn resolve_data(code: &str, prev_datum_name: &Option<String>, address: i32) -> Vec<Datum> {
+    if let [name, directive, value] = code.split('\t').collect::<Vec<&str>>()[..] {
+        let symbol = if value.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            Some(value.to_string())
+        } else {
+            None
+        };
+        let values = symbol
+            .as_ref()
+            .map_or_else(|| resolve_values(directive, value), |_| vec![0]);
         let name = name.trim_end_matches(':');
-        if name.is_empty() {
-            if let Some(prev_datum_name) = prev_datum_name {
-                let name = format!("{}_{}", prev_datum_name, address);
-                Some(Datum::new(&name, value, address))
-            } else {
-                panic!("Data name not found.")
-            }
+        let (name, is_named) = if !name.is_empty() {
+            (name.to_string(), true)
+        } else if let Some(prev_datum_name) = prev_datum_name {
+            (format!("{}_{}", prev_datum_name, address), false)
         } else {
-            Some(Datum::new(name, value, address))
+            panic!("Data name not found.")
+        };
+
+        let mut data = vec![];
+        let mut address = address;
+        if directive == ".double" && address % DOUBLE_WORD != 0 {
+            data.push(Datum {
+                is_named: false,
+                ..Datum::new(&format!("{}_{}", name, address), 0, address)
+            });
+            address += WORD;
         }
+        values.iter().enumerate().for_each(|(index, value)| {
+            let big_endian_value =
+                (directive == ".double").then(|| values[values.len() - 1 - index]);
+            let datum = if index == 0 {
+                Datum {
+                    is_named,
+                    symbol: symbol.clone(),
+                    big_endian_value,
+                    ..Datum::new(&name, *value, address)
+                }
+            } else {
+                Datum {
+                    is_named: false,
+                    big_endian_value,
+                    ..Datum::new(&format!("{}_{}", name, address), *value, address)
+                }
+            };
+            data.push(datum);
+            address += WORD;
+        });
+        data
     } else {
-        None
+        vec![]
     }
 }
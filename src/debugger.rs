@@ -0,0 +1,459 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::io::Write;
+
+use crate::constants::{KERNEL_TEXT_SECTION_MIN_ADDRESS, TEXT_SECTION_MIN_ADDRESS, WORD};
+use crate::register::{
+    find_float_register, find_register, get_float_register_name, get_register_name,
+};
+use crate::section::Section;
+use crate::simulator::{Simulator, StopReason};
+use crate::source::format_source;
+use crate::symbol::{collect_symbols, Symbol};
+use crate::text::Text;
+use crate::Program;
+
+pub const HISTORY_SIZE: usize = 1000;
+
+const HELP: &str = "Commands:
+  break <location>     Set a breakpoint on a label, source line or address (b)
+  delete <location>    Remove a breakpoint (d)
+  watch <address>      Stop when the word at a label or address changes (w)
+  unwatch <address>    Remove a watchpoint
+  step [count]         Execute instructions, stepping into calls (s)
+  next [count]         Execute instructions, stepping over jal and jalr (n)
+  continue             Run until a breakpoint, watchpoint or the end (c)
+  back [count]         Undo executed instructions (rs, reverse-step)
+  registers            Print all registers (r)
+  print <register>     Print a register, e.g. $t0 or $f0 (p)
+  x <address> [count]  Print memory words
+  list                 Print the current source line (l)
+  quit                 Exit the debugger (q)";
+
+struct Snapshot {
+    registers: [u32; 32],
+    pc: u32,
+    hi: u32,
+    lo: u32,
+    coprocessor0: [u32; 32],
+    coprocessor1: [u32; 32],
+    condition_flag: bool,
+    steps: u64,
+    heap_address: u32,
+    exit_code: Option<i32>,
+    delayed_target: Option<u32>,
+    writes: Vec<(u32, u8)>,
+}
+
+enum Stop {
+    BREAKPOINT,
+    WATCHPOINT(u32, u32, u32),
+    PROGRAM(StopReason),
+}
+
+pub struct Debugger<'a> {
+    pub simulator: Simulator,
+    program: &'a Program,
+    symbols: Vec<Symbol>,
+    max_steps: Option<u64>,
+    breakpoints: BTreeSet<u32>,
+    watchpoints: BTreeMap<u32, u32>,
+    history: VecDeque<Snapshot>,
+}
+
+fn parse_number(text: &str) -> Option<u32> {
+    match text.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => text.parse::<i64>().ok().map(|number| number as u32),
+    }
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(simulator: Simulator, program: &'a Program, max_steps: Option<u64>) -> Self {
+        Self {
+            simulator,
+            program,
+            symbols: collect_symbols(program),
+            max_steps,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeMap::new(),
+            history: VecDeque::new(),
+        }
+    }
+
+    fn print(&mut self, text: &str) {
+        writeln!(self.simulator.output, "{}", text).expect("Failed to write output.");
+    }
+
+    fn get_text(&self, address: u32) -> Option<&Text> {
+        let (texts, start_address) = if address >= KERNEL_TEXT_SECTION_MIN_ADDRESS as u32 {
+            (&self.program.kernel_texts, KERNEL_TEXT_SECTION_MIN_ADDRESS)
+        } else {
+            (&self.program.texts, TEXT_SECTION_MIN_ADDRESS)
+        };
+        let offset = address.checked_sub(start_address as u32)?;
+        if offset % WORD as u32 != 0 {
+            return None;
+        }
+        texts.get((offset / WORD as u32) as usize)
+    }
+
+    fn resolve_address(&self, text: &str) -> Option<u32> {
+        if let Some(register) = find_register(text) {
+            return Some(self.simulator.registers[register as usize]);
+        }
+        parse_number(text).or_else(|| {
+            self.symbols
+                .iter()
+                .find(|symbol| symbol.name == text)
+                .map(|symbol| symbol.address as u32)
+        })
+    }
+
+    fn resolve_location(&self, text: &str) -> Option<u32> {
+        if text.starts_with("0x") || !text.chars().all(|c| c.is_ascii_digit()) {
+            return self.resolve_address(text);
+        }
+        let line_number = text.parse::<usize>().ok()?;
+        let find_address = |texts: &[Text], start_address: i32| {
+            texts
+                .iter()
+                .position(|text| {
+                    text.source
+                        .as_ref()
+                        .is_some_and(|source| source.line_number == line_number)
+                })
+                .map(|index| (start_address + index as i32 * WORD) as u32)
+        };
+        find_address(&self.program.texts, TEXT_SECTION_MIN_ADDRESS)
+            .or_else(|| find_address(&self.program.kernel_texts, KERNEL_TEXT_SECTION_MIN_ADDRESS))
+    }
+
+    fn format_location(&self, address: u32) -> String {
+        let labels = self
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.address as u32 == address && symbol.section == Section::TEXT)
+            .map(|symbol| format!("{}:", symbol.name))
+            .collect::<Vec<String>>();
+        let source = self
+            .get_text(address)
+            .and_then(|text| text.source.as_ref())
+            .map_or_else(String::new, |source| {
+                format!("{:>5}  {}", source.line_number, format_source(&source.line))
+            });
+        let mut result = labels.join("\n");
+        if !result.is_empty() {
+            result.push('\n');
+        }
+        result.push_str(format!("{:08x}  {}", address, source).trim_end());
+        result
+    }
+
+    fn step_once(&mut self) -> Option<Stop> {
+        if let Some(stop_reason) = self.simulator.check_stop(self.max_steps) {
+            return Some(Stop::PROGRAM(stop_reason));
+        }
+
+        let simulator = &mut self.simulator;
+        let mut snapshot = Snapshot {
+            registers: simulator.registers,
+            pc: simulator.pc,
+            hi: simulator.hi,
+            lo: simulator.lo,
+            coprocessor0: simulator.coprocessor0,
+            coprocessor1: simulator.coprocessor1,
+            condition_flag: simulator.condition_flag,
+            steps: simulator.steps,
+            heap_address: simulator.heap_address,
+            exit_code: simulator.exit_code,
+            delayed_target: simulator.delayed_target,
+            writes: vec![],
+        };
+        simulator.memory.start_journal();
+        let result = simulator.step();
+        snapshot.writes = simulator.memory.take_journal();
+        if let Err(exception) = result {
+            return Some(Stop::PROGRAM(StopReason::EXCEPTION(exception)));
+        }
+
+        if self.history.len() == HISTORY_SIZE {
+            self.history.pop_front();
+        }
+        self.history.push_back(snapshot);
+
+        let changed = self.watchpoints.iter().find_map(|(&address, &value)| {
+            let current = self.simulator.memory.read_word(address);
+            (current != value).then_some((address, value, current))
+        });
+        if let Some((address, previous, current)) = changed {
+            self.watchpoints.insert(address, current);
+            return Some(Stop::WATCHPOINT(address, previous, current));
+        }
+        if self.breakpoints.contains(&self.simulator.pc) {
+            return Some(Stop::BREAKPOINT);
+        }
+        None
+    }
+
+    fn step_back(&mut self) -> bool {
+        match self.history.pop_back() {
+            Some(snapshot) => {
+                let simulator = &mut self.simulator;
+                snapshot
+                    .writes
+                    .iter()
+                    .rev()
+                    .for_each(|&(address, value)| simulator.memory.write_byte(address, value));
+                simulator.registers = snapshot.registers;
+                simulator.pc = snapshot.pc;
+                simulator.hi = snapshot.hi;
+                simulator.lo = snapshot.lo;
+                simulator.coprocessor0 = snapshot.coprocessor0;
+                simulator.coprocessor1 = snapshot.coprocessor1;
+                simulator.condition_flag = snapshot.condition_flag;
+                simulator.steps = snapshot.steps;
+                simulator.heap_address = snapshot.heap_address;
+                simulator.exit_code = snapshot.exit_code;
+                simulator.delayed_target = snapshot.delayed_target;
+                self.watchpoints = self
+                    .watchpoints
+                    .keys()
+                    .map(|&address| (address, self.simulator.memory.read_word(address)))
+                    .collect();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn is_call(&self) -> bool {
+        self.get_text(self.simulator.pc)
+            .and_then(|text| text.get_instruction())
+            .is_some_and(|instruction| matches!(instruction.name, "jal" | "jalr"))
+    }
+
+    fn step_over(&mut self) -> Option<Stop> {
+        if !self.is_call() {
+            return self.step_once();
+        }
+        let slots = if self.simulator.delay_slots { 2 } else { 1 };
+        let return_address = self.simulator.pc.wrapping_add(slots * WORD as u32);
+        let stack_pointer = self.simulator.registers[29];
+        loop {
+            if let Some(stop) = self.step_once() {
+                return Some(stop);
+            }
+            if self.simulator.pc == return_address && self.simulator.registers[29] >= stack_pointer
+            {
+                return None;
+            }
+        }
+    }
+
+    fn report(&mut self, stop: Option<Stop>) {
+        match stop {
+            Some(Stop::BREAKPOINT) => {
+                let message = format!("Breakpoint at 0x{:08x}.", self.simulator.pc);
+                self.print(&message);
+            }
+            Some(Stop::WATCHPOINT(address, previous, current)) => {
+                let message = format!(
+                    "Watchpoint 0x{:08x}: 0x{:08x} -> 0x{:08x}.",
+                    address, previous, current
+                );
+                self.print(&message);
+            }
+            Some(Stop::PROGRAM(stop_reason)) => {
+                let message = self.simulator.describe_stop_reason(stop_reason);
+                self.print(&message);
+                if stop_reason != StopReason::LIMIT {
+                    return;
+                }
+            }
+            None => {}
+        }
+        let location = self.format_location(self.simulator.pc);
+        self.print(&location);
+    }
+
+    fn execute(&mut self, command: &str, arguments: &[&str]) -> bool {
+        let count = arguments
+            .first()
+            .and_then(|argument| argument.parse::<usize>().ok())
+            .unwrap_or(1);
+        match command {
+            "break" | "b" => match arguments
+                .first()
+                .and_then(|text| self.resolve_location(text))
+            {
+                Some(address) => {
+                    self.breakpoints.insert(address);
+                    let message = format!("Breakpoint set at 0x{:08x}.", address);
+                    self.print(&message);
+                }
+                None => self.print("Unknown location."),
+            },
+            "delete" | "d" => match arguments
+                .first()
+                .and_then(|text| self.resolve_location(text))
+            {
+                Some(address) if self.breakpoints.remove(&address) => {
+                    let message = format!("Breakpoint at 0x{:08x} removed.", address);
+                    self.print(&message);
+                }
+                _ => self.print("No breakpoint at that location."),
+            },
+            "watch" | "w" => match arguments
+                .first()
+                .and_then(|text| self.resolve_address(text))
+            {
+                Some(address) if address % WORD as u32 == 0 => {
+                    let value = self.simulator.memory.read_word(address);
+                    self.watchpoints.insert(address, value);
+                    let message = format!("Watching 0x{:08x} = 0x{:08x}.", address, value);
+                    self.print(&message);
+                }
+                _ => self.print("Unknown or unaligned address."),
+            },
+            "unwatch" => match arguments
+                .first()
+                .and_then(|text| self.resolve_address(text))
+            {
+                Some(address) if self.watchpoints.remove(&address).is_some() => {
+                    let message = format!("Watchpoint at 0x{:08x} removed.", address);
+                    self.print(&message);
+                }
+                _ => self.print("No watchpoint at that address."),
+            },
+            "step" | "s" => {
+                let stop = (0..count).find_map(|_| self.step_once());
+                self.report(stop);
+            }
+            "next" | "n" => {
+                let stop = (0..count).find_map(|_| self.step_over());
+                self.report(stop);
+            }
+            "continue" | "c" => {
+                let stop = loop {
+                    if let Some(stop) = self.step_once() {
+                        break stop;
+                    }
+                };
+                self.report(Some(stop));
+            }
+            "back" | "reverse-step" | "rs" => {
+                let steps = (0..count).take_while(|_| self.step_back()).count();
+                if steps < count {
+                    self.print("Reached the start of the history.");
+                }
+                self.report(None);
+            }
+            "registers" | "r" => {
+                let registers = self.simulator.format_registers();
+                self.print(registers.trim_end());
+            }
+            "print" | "p" => match arguments.first().and_then(|text| find_register(text)) {
+                Some(register) => {
+                    let value = self.simulator.registers[register as usize];
+                    let message = format!(
+                        "{} = 0x{:08x} ({})",
+                        get_register_name(register),
+                        value,
+                        value as i32
+                    );
+                    self.print(&message);
+                }
+                None => match arguments.first().copied() {
+                    Some("pc") => {
+                        let message = format!("pc = 0x{:08x}", self.simulator.pc);
+                        self.print(&message);
+                    }
+                    Some("hi") => {
+                        let message = format!("hi = 0x{:08x}", self.simulator.hi);
+                        self.print(&message);
+                    }
+                    Some("lo") => {
+                        let message = format!("lo = 0x{:08x}", self.simulator.lo);
+                        self.print(&message);
+                    }
+                    other => match other.and_then(find_float_register) {
+                        Some(register) => {
+                            let value = self.simulator.coprocessor1[register as usize];
+                            let message = format!(
+                                "{} = 0x{:08x} ({})",
+                                get_float_register_name(register),
+                                value,
+                                f32::from_bits(value)
+                            );
+                            self.print(&message);
+                        }
+                        None => self.print("Unknown register."),
+                    },
+                },
+            },
+            "x" => match arguments
+                .first()
+                .and_then(|text| self.resolve_address(text))
+            {
+                Some(address) => {
+                    let count = arguments
+                        .get(1)
+                        .and_then(|argument| argument.parse::<u32>().ok())
+                        .unwrap_or(1);
+                    (0..count).for_each(|index| {
+                        let address = address.wrapping_add(index * WORD as u32);
+                        let value = self.simulator.memory.read_word(address);
+                        let message =
+                            format!("0x{:08x}: 0x{:08x} ({})", address, value, value as i32);
+                        self.print(&message);
+                    });
+                }
+                None => self.print("Unknown address."),
+            },
+            "list" | "l" => self.report(None),
+            "help" | "h" => self.print(HELP),
+            "quit" | "q" => return false,
+            _ => {
+                let message = format!(
+                    "Unknown command: {}. Type help for a list of commands.",
+                    command
+                );
+                self.print(&message);
+            }
+        }
+        true
+    }
+
+    pub fn run(&mut self) {
+        self.report(None);
+        loop {
+            write!(self.simulator.output, "(debug) ").expect("Failed to write output.");
+            self.simulator
+                .output
+                .flush()
+                .expect("Failed to write output.");
+
+            let mut line = String::new();
+            let count = self
+                .simulator
+                .input
+                .read_line(&mut line)
+                .expect("Failed to read input.");
+            if count == 0 {
+                self.print("");
+                break;
+            }
+
+            let words = line.split_whitespace().collect::<Vec<&str>>();
+            if let Some((command, arguments)) = words.split_first() {
+                if !self.execute(command, arguments) {
+                    break;
+                }
+            }
+        }
+        self.simulator
+            .output
+            .flush()
+            .expect("Failed to write output.");
+    }
+}
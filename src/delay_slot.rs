@@ -0,0 +1,64 @@
+use crate::directive::is_reorder;
+use crate::label::resolve_labels;
+use crate::source::Source;
+use crate::text::Text;
+
+fn is_control(text: &Text) -> bool {
+    text.get_instruction()
+        .is_some_and(|instruction| instruction.is_control())
+}
+
+fn is_movable(code: &Source, text: &Text) -> bool {
+    code.pseudo_instruction.is_none()
+        && text.get_instruction().is_some_and(|instruction| {
+            !instruction.is_control() && !matches!(instruction.name, "syscall" | "break" | "eret")
+        })
+}
+
+fn is_independent(moved: &Text, control: &Text) -> bool {
+    let (moved_sources, moved_destinations) = moved.get_register_usage();
+    let (control_sources, control_destinations) = control.get_register_usage();
+    moved_destinations.iter().all(|register| {
+        !control_sources.contains(register) && !control_destinations.contains(register)
+    }) && moved_sources
+        .iter()
+        .all(|register| !control_destinations.contains(register))
+}
+
+pub fn fill_delay_slots(codes: &[Source], texts: &[Text], modes: &[(usize, bool)]) -> Vec<Source> {
+    let mut result: Vec<Source> = vec![];
+    let mut candidate: Option<usize> = None;
+    let mut index = 0;
+    let mut is_delay_slot = false;
+    codes.iter().for_each(|code| {
+        if resolve_labels(&code.code).is_some() {
+            result.push(code.clone());
+            candidate = None;
+            return;
+        }
+
+        let text = &texts[index];
+        let reorder = is_reorder(modes, code.line_number);
+        let is_unfilled_slot = is_delay_slot;
+        is_delay_slot = is_control(text) && !reorder;
+
+        if is_control(text) && reorder {
+            match candidate.take() {
+                Some(moved) if is_independent(&texts[moved], text) => {
+                    let moved = result.pop().expect("Moved instruction is missing.");
+                    result.push(code.clone());
+                    result.push(moved);
+                }
+                _ => {
+                    result.push(code.clone());
+                    result.push(Source::new_nop(code.line_number));
+                }
+            }
+        } else {
+            candidate = (reorder && !is_unfilled_slot && is_movable(code, text)).then_some(index);
+            result.push(code.clone());
+        }
+        index += 1;
+    });
+    result
+}
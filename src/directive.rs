@@ -0,0 +1,50 @@
+use crate::line::Line;
+
+pub enum Directive {
+    GLOBL(String),
+    EXTERN(String),
+    SET(String),
+}
+
+pub fn resolve_directive(code: &str) -> Option<Directive> {
+    if let [_, name, argument] = code.split('\t').collect::<Vec<&str>>()[..] {
+        match name {
+            ".globl" | ".global" => Some(Directive::GLOBL(argument.trim().to_string())),
+            ".extern" => Some(Directive::EXTERN(argument.trim().to_string())),
+            ".set" => Some(Directive::SET(argument.trim().to_string())),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+pub fn extract_globals(lines: &[Line]) -> Vec<String> {
+    lines
+        .iter()
+        .filter_map(|line| line.text.as_ref())
+        .filter_map(|text| match resolve_directive(text) {
+            Some(Directive::GLOBL(name)) | Some(Directive::EXTERN(name)) => Some(name),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn extract_reorder_modes(lines: &[Line]) -> Vec<(usize, bool)> {
+    lines
+        .iter()
+        .filter_map(|line| match resolve_directive(line.text.as_ref()?) {
+            Some(Directive::SET(option)) if option == "reorder" => Some((line.number, true)),
+            Some(Directive::SET(option)) if option == "noreorder" => Some((line.number, false)),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn is_reorder(modes: &[(usize, bool)], line_number: usize) -> bool {
+    modes
+        .iter()
+        .rev()
+        .find(|(number, _)| *number <= line_number)
+        .is_none_or(|(_, reorder)| *reorder)
+}
@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+
+use crate::constants::WORD;
+use crate::datum::Datum;
+use crate::input::Image;
+use crate::instruction::{convert_opcode_to_format, InstructionFormat};
+use crate::pseudo_instruction::disassemble_pseudo_instruction;
+use crate::register::{get_float_register_name, get_register_name};
+use crate::text::{get_text_from_code, Text};
+
+pub type Labels = BTreeMap<u32, Vec<String>>;
+
+fn get_target_address(text: &Text, address: u32) -> Option<u32> {
+    let instruction = text.get_instruction()?;
+    match convert_opcode_to_format(text.opcode, text.rs) {
+        InstructionFormat::JUMP => {
+            Some((address.wrapping_add(WORD as u32) & 0xf000_0000) | ((text.address as u32) << 2))
+        }
+        InstructionFormat::IMMEDIATE if instruction.is_branch() => {
+            let offset = (text.immediate as i16 as i32) << 2;
+            Some(
+                address
+                    .wrapping_add(WORD as u32)
+                    .wrapping_add(offset as u32),
+            )
+        }
+        _ => None,
+    }
+}
+
+fn render_target(target: u32, labels: &Labels, is_jump: bool) -> String {
+    match labels.get(&target).and_then(|names| names.first()) {
+        Some(name) => name.clone(),
+        None if is_jump => format!("0x{:x}", target),
+        None => (target as i32).to_string(),
+    }
+}
+
+pub fn render_instruction(text: &Text, address: u32, labels: &Labels) -> Option<String> {
+    let instruction = text.get_instruction()?;
+    let name = instruction.name;
+    let rs = get_register_name(text.rs);
+    let rt = get_register_name(text.rt);
+    let rd = get_register_name(text.rd);
+    let ft = get_float_register_name(text.rt);
+    let fs = get_float_register_name(text.rd);
+    let fd = get_float_register_name(text.shamt);
+
+    match convert_opcode_to_format(text.opcode, text.rs) {
+        InstructionFormat::REGISTER if name == "eret" => {
+            (text.rt == 0 && text.rd == 0 && text.shamt == 0).then(|| name.to_string())
+        }
+        InstructionFormat::REGISTER if instruction.is_float_compare() => {
+            (text.shamt == 0).then(|| format!("{}\t{}, {}", name, fs, ft))
+        }
+        InstructionFormat::REGISTER if instruction.is_float_operation() && text.funct < 4 => {
+            Some(format!("{}\t{}, {}, {}", name, fd, fs, ft))
+        }
+        InstructionFormat::REGISTER if instruction.is_float_operation() => {
+            (text.rt == 0).then(|| format!("{}\t{}, {}", name, fd, fs))
+        }
+        InstructionFormat::REGISTER if instruction.is_float() => {
+            (text.shamt == 0).then(|| format!("{}\t{}, {}", name, rt, fs))
+        }
+        InstructionFormat::REGISTER if instruction.is_coprocessor() => {
+            (text.shamt == 0).then(|| format!("{}\t{}, ${}", name, rt, text.rd))
+        }
+        InstructionFormat::REGISTER if instruction.is_trap() => {
+            (text.rs == 0 && text.rt == 0 && text.rd == 0 && text.shamt == 0)
+                .then(|| name.to_string())
+        }
+        InstructionFormat::REGISTER if instruction.is_shift() => {
+            (text.rs == 0).then(|| format!("{}\t{}, {}, {}", name, rd, rt, text.shamt))
+        }
+        InstructionFormat::REGISTER if instruction.is_variable_shift() => {
+            (text.shamt == 0).then(|| format!("{}\t{}, {}, {}", name, rd, rt, rs))
+        }
+        InstructionFormat::REGISTER if name == "jalr" && text.rd == 31 => {
+            (text.rt == 0 && text.shamt == 0).then(|| format!("{}\t{}", name, rs))
+        }
+        InstructionFormat::REGISTER if name == "jalr" => {
+            (text.rt == 0 && text.shamt == 0).then(|| format!("{}\t{}, {}", name, rd, rs))
+        }
+        InstructionFormat::REGISTER
+            if instruction.is_register_jump() || instruction.is_move_to_hi_lo() =>
+        {
+            (text.rt == 0 && text.rd == 0 && text.shamt == 0).then(|| format!("{}\t{}", name, rs))
+        }
+        InstructionFormat::REGISTER if instruction.is_move_from_hi_lo() => {
+            (text.rs == 0 && text.rt == 0 && text.shamt == 0).then(|| format!("{}\t{}", name, rd))
+        }
+        InstructionFormat::REGISTER if instruction.is_multiply_divide() => {
+            (text.rd == 0 && text.shamt == 0).then(|| format!("{}\t{}, {}", name, rs, rt))
+        }
+        InstructionFormat::REGISTER => {
+            (text.shamt == 0).then(|| format!("{}\t{}, {}, {}", name, rd, rs, rt))
+        }
+        InstructionFormat::JUMP => {
+            let target = get_target_address(text, address)?;
+            Some(format!("{}\t{}", name, render_target(target, labels, true)))
+        }
+        InstructionFormat::IMMEDIATE if instruction.is_branch_zero() => {
+            let target = get_target_address(text, address)?;
+            (text.rt == 0)
+                .then(|| format!("{}\t{}, {}", name, rs, render_target(target, labels, false)))
+        }
+        InstructionFormat::IMMEDIATE if instruction.is_coprocessor_branch() => {
+            let target = get_target_address(text, address)?;
+            Some(format!(
+                "{}\t{}",
+                name,
+                render_target(target, labels, false)
+            ))
+        }
+        InstructionFormat::IMMEDIATE if instruction.is_branch() => {
+            let target = get_target_address(text, address)?;
+            Some(format!(
+                "{}\t{}, {}, {}",
+                name,
+                rs,
+                rt,
+                render_target(target, labels, false)
+            ))
+        }
+        InstructionFormat::IMMEDIATE if name == "lui" => {
+            (text.rs == 0).then(|| format!("{}\t{}, 0x{:x}", name, rt, text.immediate))
+        }
+        InstructionFormat::IMMEDIATE if instruction.is_float_load_store() => Some(format!(
+            "{}\t{}, {}({})",
+            name, ft, text.immediate as i16, rs
+        )),
+        InstructionFormat::IMMEDIATE if instruction.is_load_store() => Some(format!(
+            "{}\t{}, {}({})",
+            name, rt, text.immediate as i16, rs
+        )),
+        InstructionFormat::IMMEDIATE if instruction.is_zero_extended() => {
+            Some(format!("{}\t{}, {}, 0x{:x}", name, rt, rs, text.immediate))
+        }
+        InstructionFormat::IMMEDIATE => Some(format!(
+            "{}\t{}, {}, {}",
+            name, rt, rs, text.immediate as i16
+        )),
+        InstructionFormat::PSEUDO => None,
+    }
+}
+
+struct PseudoInstruction {
+    code: String,
+    address: Option<u32>,
+    count: usize,
+}
+
+fn is_expansion_of(code: &str, data: &[Datum], texts: &[Text]) -> bool {
+    disassemble_pseudo_instruction(code, data, false).is_some_and(|codes| {
+        codes.len() == texts.len()
+            && codes.iter().zip(texts).all(|(code, text)| {
+                get_text_from_code(code, 0, &[], &[], &[]).to_word() == text.to_word()
+            })
+    })
+}
+
+fn recognize_la(texts: &[Text]) -> Option<PseudoInstruction> {
+    let lui = texts.first()?;
+    if lui.get_instruction()?.name != "lui" || lui.rs != 0 {
+        return None;
+    }
+    let register = get_register_name(lui.rt);
+    let upper_address = (lui.immediate as u32) << 16;
+    let ori_address = texts
+        .get(1)
+        .filter(|ori| {
+            ori.get_instruction()
+                .is_some_and(|instruction| instruction.name == "ori")
+                && ori.rs == lui.rt
+                && ori.rt == lui.rt
+        })
+        .map(|ori| upper_address | ori.immediate as u32);
+
+    ori_address
+        .iter()
+        .map(|address| (*address, 2))
+        .chain([(upper_address, 1)])
+        .find(|(address, count)| {
+            let code = format!("\tla\t{}, symbol", register);
+            let data = [Datum::new("symbol", 0, *address as i32)];
+            texts
+                .get(..*count)
+                .is_some_and(|texts| is_expansion_of(&code, &data, texts))
+        })
+        .map(|(address, count)| PseudoInstruction {
+            code: format!("la\t{}, ", register),
+            address: Some(address),
+            count,
+        })
+}
+
+fn recognize_pseudo_instruction(texts: &[Text]) -> Option<PseudoInstruction> {
+    let text = texts.first()?;
+    let instruction = text.get_instruction()?;
+    if text.to_word() == 0 {
+        Some(PseudoInstruction {
+            code: String::from("nop"),
+            address: None,
+            count: 1,
+        })
+    } else if instruction.name == "addu" && text.rt == 0 && text.shamt == 0 {
+        Some(PseudoInstruction {
+            code: format!(
+                "move\t{}, {}",
+                get_register_name(text.rd),
+                get_register_name(text.rs)
+            ),
+            address: None,
+            count: 1,
+        })
+    } else {
+        recognize_la(texts)
+    }
+}
+
+fn is_data_address(image: &Image, address: u32) -> bool {
+    let data_end_address = image.data_address + (image.data.len() as u32) * WORD as u32;
+    (image.data_address..data_end_address).contains(&address)
+}
+
+fn collect_labels(image: &Image, texts: &[Text], recognize_pseudo: bool) -> Labels {
+    let mut labels = Labels::new();
+    image.symbols.iter().for_each(|symbol| {
+        labels
+            .entry(symbol.address)
+            .or_default()
+            .push(symbol.name.clone())
+    });
+
+    let text_end_address = image.text_address + (texts.len() as u32) * WORD as u32;
+    texts.iter().enumerate().for_each(|(index, text)| {
+        let address = image.text_address + (index as u32) * WORD as u32;
+        if let Some(target) = get_target_address(text, address) {
+            if (image.text_address..=text_end_address).contains(&target) {
+                labels
+                    .entry(target)
+                    .or_insert_with(|| vec![format!("label_{:08x}", target)]);
+            }
+        }
+    });
+
+    if recognize_pseudo {
+        (0..texts.len())
+            .filter_map(|index| recognize_la(&texts[index..]))
+            .filter_map(|pseudo_instruction| pseudo_instruction.address)
+            .filter(|address| is_data_address(image, *address))
+            .for_each(|address| {
+                labels
+                    .entry(address)
+                    .or_insert_with(|| vec![format!("data_{:08x}", address)]);
+            });
+    }
+
+    if !image.data.is_empty() {
+        labels
+            .entry(image.data_address)
+            .or_insert_with(|| vec![format!("data_{:08x}", image.data_address)]);
+    }
+    labels
+}
+
+pub fn disassemble(image: &Image, recognize_pseudo: bool) -> String {
+    let texts = image
+        .text
+        .iter()
+        .map(|word| Text::from_word(*word))
+        .collect::<Vec<Text>>();
+    let labels = collect_labels(image, &texts, recognize_pseudo);
+    let mut lines: Vec<String> = vec![];
+
+    if !image.data.is_empty() {
+        lines.push(String::from("\t.data"));
+        image.data.iter().enumerate().for_each(|(index, word)| {
+            let address = image.data_address + (index as u32) * WORD as u32;
+            let name = labels
+                .get(&address)
+                .and_then(|names| names.first())
+                .map_or_else(String::new, |name| format!("{}:", name));
+            lines.push(format!("{}\t.word\t{}", name, *word as i32));
+        });
+    }
+
+    lines.push(String::from("\t.text"));
+    if image.delay_slots {
+        lines.push(String::from("\t.set\tnoreorder"));
+    }
+    image
+        .symbols
+        .iter()
+        .filter(|symbol| symbol.is_global)
+        .for_each(|symbol| lines.push(format!("\t.globl\t{}", symbol.name)));
+
+    let text_label_lines = |address: u32| {
+        labels.get(&address).map_or_else(Vec::new, |names| {
+            names.iter().map(|name| format!("{}:", name)).collect()
+        })
+    };
+    let mut index = 0;
+    while index < texts.len() {
+        let address = image.text_address + (index as u32) * WORD as u32;
+        lines.extend(text_label_lines(address));
+
+        let pseudo_instruction = recognize_pseudo
+            .then(|| recognize_pseudo_instruction(&texts[index..]))
+            .flatten()
+            .filter(|pseudo_instruction| {
+                (1..pseudo_instruction.count)
+                    .all(|offset| !labels.contains_key(&(address + (offset as u32) * WORD as u32)))
+            })
+            .and_then(|pseudo_instruction| match pseudo_instruction.address {
+                Some(target) => labels
+                    .get(&target)
+                    .filter(|_| is_data_address(image, target))
+                    .and_then(|names| names.first())
+                    .map(|name| PseudoInstruction {
+                        code: format!("{}{}", pseudo_instruction.code, name),
+                        ..pseudo_instruction
+                    }),
+                None => Some(pseudo_instruction),
+            });
+
+        match pseudo_instruction {
+            Some(pseudo_instruction) => {
+                let encoding = texts[index..index + pseudo_instruction.count]
+                    .iter()
+                    .map(|text| format!("0x{:08x}", text.to_word()))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                lines.push(format!("\t{}\t# {}", pseudo_instruction.code, encoding));
+                index += pseudo_instruction.count;
+            }
+            None => {
+                let text = &texts[index];
+                lines.push(match render_instruction(text, address, &labels) {
+                    Some(instruction) => format!("\t{}", instruction),
+                    None => format!("\t.word\t0x{:08x}", text.to_word()),
+                });
+                index += 1;
+            }
+        }
+    }
+    lines.extend(text_label_lines(
+        image.text_address + (texts.len() as u32) * WORD as u32,
+    ));
+
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
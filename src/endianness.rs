@@ -0,0 +1,54 @@
+use std::convert::TryInto;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Endianness {
+    BIG,
+    LITTLE,
+}
+
+impl Endianness {
+    pub fn word_to_bytes(self, word: u32) -> [u8; 4] {
+        match self {
+            Endianness::BIG => word.to_be_bytes(),
+            Endianness::LITTLE => word.to_le_bytes(),
+        }
+    }
+
+    pub fn half_to_bytes(self, half: u16) -> [u8; 2] {
+        match self {
+            Endianness::BIG => half.to_be_bytes(),
+            Endianness::LITTLE => half.to_le_bytes(),
+        }
+    }
+
+    pub fn bytes_to_word(self, bytes: &[u8]) -> u32 {
+        let bytes: [u8; 4] = bytes[..4].try_into().unwrap();
+        match self {
+            Endianness::BIG => u32::from_be_bytes(bytes),
+            Endianness::LITTLE => u32::from_le_bytes(bytes),
+        }
+    }
+
+    pub fn bytes_to_half(self, bytes: &[u8]) -> u16 {
+        let bytes: [u8; 2] = bytes[..2].try_into().unwrap();
+        match self {
+            Endianness::BIG => u16::from_be_bytes(bytes),
+            Endianness::LITTLE => u16::from_le_bytes(bytes),
+        }
+    }
+
+    pub fn from_ident(ident: u8) -> Option<Self> {
+        match ident {
+            1 => Some(Endianness::LITTLE),
+            2 => Some(Endianness::BIG),
+            _ => None,
+        }
+    }
+
+    pub fn to_ident(self) -> u8 {
+        match self {
+            Endianness::LITTLE => 1,
+            Endianness::BIG => 2,
+        }
+    }
+}
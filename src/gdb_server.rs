@@ -0,0 +1,338 @@
+use std::collections::BTreeSet;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+
+use crate::simulator::coprocessor0;
+use crate::simulator::{Exception, Simulator, StopReason};
+
+const REGISTER_COUNT: usize = 72;
+const STATUS_REGISTER: usize = 32;
+const LO_REGISTER: usize = 33;
+const HI_REGISTER: usize = 34;
+const BAD_ADDRESS_REGISTER: usize = 35;
+const CAUSE_REGISTER: usize = 36;
+const PC_REGISTER: usize = 37;
+const FIRST_FLOAT_REGISTER: usize = 38;
+const LAST_FLOAT_REGISTER: usize = 69;
+const FLOAT_CONTROL_REGISTER: usize = 70;
+const FLOAT_IMPLEMENTATION_REGISTER: usize = 71;
+
+const CONDITION_FLAG: u32 = 1 << 23;
+
+const INTERRUPT: u8 = 0x03;
+const INTERRUPT_CHECK_STEPS: u64 = 0x10000;
+
+const SIGINT: u8 = 2;
+const SIGILL: u8 = 4;
+const SIGTRAP: u8 = 5;
+const SIGFPE: u8 = 8;
+const SIGBUS: u8 = 10;
+const SIGSYS: u8 = 12;
+
+pub struct GdbServer {
+    pub simulator: Simulator,
+    max_steps: Option<u64>,
+    breakpoints: BTreeSet<u32>,
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(text.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+fn parse_address_length(text: &str) -> Option<(u32, u32)> {
+    let (address, length) = text.split_once(',')?;
+    Some((
+        u32::from_str_radix(address, 16).ok()?,
+        u32::from_str_radix(length, 16).ok()?,
+    ))
+}
+
+fn compose_packet(data: &str) -> String {
+    let checksum = data.bytes().fold(0u8, |sum, byte| sum.wrapping_add(byte));
+    format!("${}#{:02x}", data, checksum)
+}
+
+fn read_packet(stream: &mut TcpStream) -> Option<String> {
+    let mut byte = [0u8; 1];
+    loop {
+        stream.read_exact(&mut byte).ok()?;
+        match byte[0] {
+            b'$' => break,
+            INTERRUPT => return Some(String::from("\x03")),
+            _ => {}
+        }
+    }
+
+    let mut data: Vec<u8> = vec![];
+    loop {
+        stream.read_exact(&mut byte).ok()?;
+        if byte[0] == b'#' {
+            break;
+        }
+        data.push(byte[0]);
+    }
+    let mut checksum = [0u8; 2];
+    stream.read_exact(&mut checksum).ok()?;
+
+    let expected = u8::from_str_radix(std::str::from_utf8(&checksum).ok()?, 16).ok()?;
+    let actual = data.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    if expected == actual {
+        stream.write_all(b"+").ok()?;
+        Some(String::from_utf8_lossy(&data).into_owned())
+    } else {
+        stream.write_all(b"-").ok()?;
+        read_packet(stream)
+    }
+}
+
+fn is_interrupted(stream: &mut TcpStream) -> bool {
+    let mut byte = [0u8; 1];
+    stream
+        .set_nonblocking(true)
+        .expect("Failed to configure GDB connection.");
+    let result = stream.read(&mut byte);
+    stream
+        .set_nonblocking(false)
+        .expect("Failed to configure GDB connection.");
+    match result {
+        Ok(1) => byte[0] == INTERRUPT,
+        Err(error) => error.kind() != ErrorKind::WouldBlock,
+        _ => true,
+    }
+}
+
+impl GdbServer {
+    pub fn new(simulator: Simulator, max_steps: Option<u64>) -> Self {
+        Self {
+            simulator,
+            max_steps,
+            breakpoints: BTreeSet::new(),
+        }
+    }
+
+    fn read_register(&self, register: usize) -> Option<u32> {
+        let simulator = &self.simulator;
+        match register {
+            0..=31 => Some(simulator.registers[register]),
+            STATUS_REGISTER => Some(simulator.coprocessor0[coprocessor0::STATUS_REGISTER]),
+            LO_REGISTER => Some(simulator.lo),
+            HI_REGISTER => Some(simulator.hi),
+            BAD_ADDRESS_REGISTER => {
+                Some(simulator.coprocessor0[coprocessor0::BAD_ADDRESS_REGISTER])
+            }
+            CAUSE_REGISTER => Some(simulator.coprocessor0[coprocessor0::CAUSE_REGISTER]),
+            PC_REGISTER => Some(simulator.pc),
+            FIRST_FLOAT_REGISTER..=LAST_FLOAT_REGISTER => {
+                Some(simulator.coprocessor1[register - FIRST_FLOAT_REGISTER])
+            }
+            FLOAT_CONTROL_REGISTER => Some(if simulator.condition_flag {
+                CONDITION_FLAG
+            } else {
+                0
+            }),
+            FLOAT_IMPLEMENTATION_REGISTER => Some(0),
+            _ => None,
+        }
+    }
+
+    fn write_register(&mut self, register: usize, value: u32) -> bool {
+        let simulator = &mut self.simulator;
+        match register {
+            1..=31 => simulator.registers[register] = value,
+            0 => {}
+            STATUS_REGISTER => simulator.coprocessor0[coprocessor0::STATUS_REGISTER] = value,
+            BAD_ADDRESS_REGISTER => {
+                simulator.coprocessor0[coprocessor0::BAD_ADDRESS_REGISTER] = value
+            }
+            CAUSE_REGISTER => simulator.coprocessor0[coprocessor0::CAUSE_REGISTER] = value,
+            LO_REGISTER => simulator.lo = value,
+            HI_REGISTER => simulator.hi = value,
+            PC_REGISTER => simulator.pc = value,
+            FIRST_FLOAT_REGISTER..=LAST_FLOAT_REGISTER => {
+                simulator.coprocessor1[register - FIRST_FLOAT_REGISTER] = value
+            }
+            FLOAT_CONTROL_REGISTER => simulator.condition_flag = value & CONDITION_FLAG != 0,
+            FLOAT_IMPLEMENTATION_REGISTER => {}
+            _ => return false,
+        }
+        true
+    }
+
+    fn encode_register(&self, value: u32) -> String {
+        encode_hex(&self.simulator.memory.endianness.word_to_bytes(value))
+    }
+
+    fn decode_register(&self, text: &str) -> Option<u32> {
+        let bytes = decode_hex(text)?;
+        (bytes.len() == 4).then(|| self.simulator.memory.endianness.bytes_to_word(&bytes))
+    }
+
+    fn compose_stop_reply(&self, stop_reason: Option<StopReason>) -> String {
+        match stop_reason {
+            None | Some(StopReason::LIMIT) => format!("S{:02x}", SIGTRAP),
+            Some(StopReason::END) => String::from("W00"),
+            Some(StopReason::EXIT(exit_code)) => format!("W{:02x}", exit_code as u8),
+            Some(StopReason::EXCEPTION(exception)) => {
+                let signal = match exception {
+                    Exception::ADEL(_) | Exception::ADES(_) => SIGBUS,
+                    Exception::SYS => SIGSYS,
+                    Exception::BP => SIGTRAP,
+                    Exception::RI(_) => SIGILL,
+                    Exception::OV => SIGFPE,
+                };
+                format!("S{:02x}", signal)
+            }
+        }
+    }
+
+    fn step(&mut self) -> Option<StopReason> {
+        if let Some(stop_reason) = self.simulator.check_stop(self.max_steps) {
+            return Some(stop_reason);
+        }
+        self.simulator.step().err().map(StopReason::EXCEPTION)
+    }
+
+    fn resume(&mut self, stream: &mut TcpStream, arguments: &str, is_step: bool) -> String {
+        if let Ok(address) = u32::from_str_radix(arguments, 16) {
+            self.simulator.pc = address;
+        }
+        if is_step {
+            let stop_reason = self.step();
+            return self.compose_stop_reply(stop_reason);
+        }
+
+        loop {
+            if let Some(stop_reason) = self.step() {
+                return self.compose_stop_reply(Some(stop_reason));
+            }
+            if self.breakpoints.contains(&self.simulator.pc) {
+                return self.compose_stop_reply(None);
+            }
+            if self.simulator.steps.is_multiple_of(INTERRUPT_CHECK_STEPS) && is_interrupted(stream)
+            {
+                return format!("S{:02x}", SIGINT);
+            }
+        }
+    }
+
+    fn handle_packet(&mut self, stream: &mut TcpStream, packet: &str) -> Option<String> {
+        let (command, arguments) = packet.split_at(packet.chars().next().map_or(0, char::len_utf8));
+        let reply = match command {
+            "\x03" => format!("S{:02x}", SIGINT),
+            "?" => format!("S{:02x}", SIGTRAP),
+            "g" => (0..REGISTER_COUNT)
+                .map(|register| self.encode_register(self.read_register(register).unwrap_or(0)))
+                .collect(),
+            "G" => {
+                let values = (0..REGISTER_COUNT)
+                    .map_while(|register| {
+                        let text = arguments.get(register * 8..register * 8 + 8)?;
+                        self.decode_register(text)
+                    })
+                    .collect::<Vec<u32>>();
+                values.iter().enumerate().for_each(|(register, value)| {
+                    self.write_register(register, *value);
+                });
+                String::from("OK")
+            }
+            "p" => usize::from_str_radix(arguments, 16)
+                .ok()
+                .and_then(|register| self.read_register(register))
+                .map_or_else(|| String::from("E00"), |value| self.encode_register(value)),
+            "P" => {
+                let written = arguments.split_once('=').and_then(|(register, value)| {
+                    let register = usize::from_str_radix(register, 16).ok()?;
+                    let value = self.decode_register(value)?;
+                    self.write_register(register, value).then_some(())
+                });
+                written.map_or_else(|| String::from("E00"), |_| String::from("OK"))
+            }
+            "m" => match parse_address_length(arguments) {
+                Some((address, length)) => {
+                    encode_hex(&self.simulator.memory.read_bytes(address, length))
+                }
+                None => String::from("E00"),
+            },
+            "M" => {
+                let written = arguments.split_once(':').and_then(|(range, data)| {
+                    let (address, length) = parse_address_length(range)?;
+                    let bytes = decode_hex(data)?;
+                    (bytes.len() == length as usize).then(|| {
+                        self.simulator.memory.write_bytes(address, &bytes);
+                    })
+                });
+                written.map_or_else(|| String::from("E00"), |_| String::from("OK"))
+            }
+            "Z" | "z" => {
+                let address = arguments
+                    .strip_prefix("0,")
+                    .and_then(|arguments| arguments.split(',').next())
+                    .and_then(|address| u32::from_str_radix(address, 16).ok());
+                match address {
+                    Some(address) => {
+                        if command == "Z" {
+                            self.breakpoints.insert(address);
+                        } else {
+                            self.breakpoints.remove(&address);
+                        }
+                        String::from("OK")
+                    }
+                    None => String::new(),
+                }
+            }
+            "s" => self.resume(stream, arguments, true),
+            "c" => self.resume(stream, arguments, false),
+            "H" => String::from("OK"),
+            "T" => String::from("OK"),
+            "D" => {
+                stream.write_all(compose_packet("OK").as_bytes()).ok()?;
+                return None;
+            }
+            "k" => return None,
+            "q" => match arguments.split(':').next() {
+                Some("Supported") => String::from("PacketSize=4000"),
+                Some("Attached") => String::from("1"),
+                Some("C") => String::from("QC1"),
+                Some("fThreadInfo") => String::from("m1"),
+                Some("sThreadInfo") => String::from("l"),
+                _ => String::new(),
+            },
+            _ => String::new(),
+        };
+        Some(reply)
+    }
+
+    pub fn serve(&mut self, listener: TcpListener) {
+        let (mut stream, _) = listener.accept().expect("Failed to accept GDB connection.");
+        stream
+            .set_nodelay(true)
+            .expect("Failed to configure GDB connection.");
+
+        while let Some(packet) = read_packet(&mut stream) {
+            let reply = match self.handle_packet(&mut stream, &packet) {
+                Some(reply) => reply,
+                None => break,
+            };
+            if stream.write_all(compose_packet(&reply).as_bytes()).is_err() {
+                break;
+            }
+            if reply.starts_with('W') {
+                break;
+            }
+        }
+        self.simulator
+            .output
+            .flush()
+            .expect("Failed to write output.");
+    }
+}
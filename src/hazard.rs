@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
+
+use crate::constants::{TEXT_SECTION_MIN_ADDRESS, WORD};
+use crate::label::resolve_labels;
+use crate::register::get_register_label;
+use crate::source::{format_source, Source};
+use crate::text::Text;
+use crate::Program;
+
+pub const DEFAULT_HAZARD_WINDOW: usize = 3;
+
+const NO_FORWARDING_DISTANCE: usize = 3;
+
+pub struct Hazard {
+    pub producer: usize,
+    pub consumer: usize,
+    pub register: i32,
+    pub distance: usize,
+    pub forwarding: Option<&'static str>,
+    pub stalls: usize,
+    pub stalls_without_forwarding: usize,
+    pub is_load_use: bool,
+}
+
+fn find_producer(texts: &[Text], consumer: usize, register: i32, window: usize) -> Option<usize> {
+    (consumer.saturating_sub(window)..consumer)
+        .rev()
+        .find(|&index| texts[index].get_register_usage().1.contains(&register))
+}
+
+pub fn get_sources(text: &Text) -> Vec<i32> {
+    let (mut sources, _) = text.get_register_usage();
+    sources.sort_unstable();
+    sources.dedup();
+    sources
+}
+
+pub fn find_hazards(texts: &[Text], window: usize) -> Vec<Hazard> {
+    texts
+        .iter()
+        .enumerate()
+        .flat_map(|(consumer, text)| {
+            let is_control = is_control(text);
+            get_sources(text)
+                .into_iter()
+                .filter_map(|register| {
+                    let producer = find_producer(texts, consumer, register, window)?;
+                    let distance = consumer - producer;
+                    let is_load = texts[producer]
+                        .get_instruction()
+                        .is_some_and(|instruction| instruction.is_load());
+                    let required_distance = match (is_control, is_load) {
+                        (false, false) => 1,
+                        (false, true) | (true, false) => 2,
+                        (true, true) => 3,
+                    };
+                    let forwarding = match (is_control, distance.max(required_distance)) {
+                        (false, 1) => Some("EX/MEM -> EX"),
+                        (false, 2) => Some("MEM/WB -> EX"),
+                        (true, 2) => Some("EX/MEM -> ID"),
+                        (true, 3) => Some("MEM/WB -> ID"),
+                        _ => None,
+                    };
+                    let stalls = required_distance.saturating_sub(distance);
+                    Some(Hazard {
+                        producer,
+                        consumer,
+                        register,
+                        distance,
+                        forwarding,
+                        stalls,
+                        stalls_without_forwarding: NO_FORWARDING_DISTANCE.saturating_sub(distance),
+                        is_load_use: is_load && stalls > 0,
+                    })
+                })
+                .collect::<Vec<Hazard>>()
+        })
+        .collect()
+}
+
+fn is_control(text: &Text) -> bool {
+    text.get_instruction()
+        .is_some_and(|instruction| instruction.is_control())
+}
+
+pub fn insert_nops(codes: &[Source], texts: &[Text], delay_slots: bool) -> Vec<Source> {
+    let mut result: Vec<Source> = vec![];
+    let mut positions: Vec<usize> = vec![];
+    let mut position = 0;
+    let mut control_index = 0;
+    codes.iter().for_each(|code| {
+        if resolve_labels(&code.code).is_some() {
+            result.push(code.clone());
+            return;
+        }
+
+        let consumer = positions.len();
+        let nops = get_sources(&texts[consumer])
+            .into_iter()
+            .filter_map(|register| {
+                let producer = find_producer(texts, consumer, register, NO_FORWARDING_DISTANCE)?;
+                Some(NO_FORWARDING_DISTANCE.saturating_sub(position - positions[producer]))
+            })
+            .max()
+            .unwrap_or(0);
+
+        let is_delay_slot = delay_slots && consumer > 0 && is_control(&texts[consumer - 1]);
+        let index = if is_delay_slot {
+            positions[consumer - 1] += nops;
+            control_index
+        } else {
+            result.len()
+        };
+        result.splice(
+            index..index,
+            (0..nops).map(|_| Source::new_nop(code.line_number)),
+        );
+        position += nops;
+        positions.push(position);
+        position += 1;
+        if is_control(&texts[consumer]) {
+            control_index = result.len();
+        }
+        result.push(code.clone());
+    });
+    result
+}
+
+fn count_stall_cycles(hazards: &[Hazard], get_stalls: fn(&Hazard) -> usize) -> usize {
+    let mut stalls: BTreeMap<usize, usize> = BTreeMap::new();
+    hazards.iter().for_each(|hazard| {
+        let count = stalls.entry(hazard.consumer).or_default();
+        *count = (*count).max(get_stalls(hazard));
+    });
+    stalls.values().sum()
+}
+
+fn compose_location(program: &Program, index: usize) -> (String, String, String) {
+    let text = &program.texts[index];
+    let address = TEXT_SECTION_MIN_ADDRESS + index as i32 * WORD;
+    let (line_number, line) = text.source.as_ref().map_or_else(
+        || (String::new(), String::new()),
+        |source| (source.line_number.to_string(), format_source(&source.code)),
+    );
+    (format!("{:08x}", address), line_number, line)
+}
+
+pub fn write_hazard_report(program: &Program, window: usize, filepath: Option<&str>) {
+    let hazards = find_hazards(&program.texts, window);
+    let mut result = format!("Hazards within {} instructions\n", window);
+    result.push_str(&format!(
+        "{:<8}  {:>4}  {:<8}  {:>4}  {:<8}  {:>8}  {:<12}  {:<10}  {:>20}  {}\n",
+        "Producer",
+        "Line",
+        "Consumer",
+        "Line",
+        "Register",
+        "Distance",
+        "Forwarding",
+        "Stalls",
+        "No-forwarding stalls",
+        "Instructions"
+    ));
+    hazards.iter().for_each(|hazard| {
+        let (producer_address, producer_line, producer_code) =
+            compose_location(program, hazard.producer);
+        let (consumer_address, consumer_line, consumer_code) =
+            compose_location(program, hazard.consumer);
+        let stalls = if hazard.is_load_use {
+            format!("{} load-use", hazard.stalls)
+        } else {
+            hazard.stalls.to_string()
+        };
+        result.push_str(&format!(
+            "{}  {:>4}  {}  {:>4}  {:<8}  {:>8}  {:<12}  {:<10}  {:>20}  {} -> {}\n",
+            producer_address,
+            producer_line,
+            consumer_address,
+            consumer_line,
+            get_register_label(hazard.register),
+            hazard.distance,
+            hazard.forwarding.unwrap_or("-"),
+            stalls,
+            hazard.stalls_without_forwarding,
+            producer_code,
+            consumer_code
+        ));
+    });
+    result.push_str(&format!(
+        "{} dependencies, {} forwarded, {} load-use stalls, {} stall cycles with forwarding, {} without forwarding\n",
+        hazards.len(),
+        hazards.iter().filter(|hazard| hazard.forwarding.is_some()).count(),
+        hazards.iter().filter(|hazard| hazard.is_load_use).count(),
+        count_stall_cycles(&hazards, |hazard| hazard.stalls),
+        count_stall_cycles(&hazards, |hazard| hazard.stalls_without_forwarding)
+    ));
+
+    match filepath {
+        Some(filepath) => {
+            let mut file = File::create(filepath).expect("Failed to create hazard report file.");
+            write!(file, "{}", result).expect("Failed to write hazard report file.");
+        }
+        None => print!("{}", result),
+    }
+}
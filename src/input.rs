@@ -0,0 +1,102 @@
+use crate::constants::{DATA_SECTION_MIN_ADDRESS, TEXT_SECTION_MIN_ADDRESS};
+use crate::endianness::Endianness;
+
+mod bin;
+mod bits;
+mod elf;
+mod hex;
+mod ihex;
+mod raw;
+
+use crate::input::bin::Bin;
+use crate::input::bits::Bits;
+use crate::input::elf::Elf;
+use crate::input::hex::Hex;
+use crate::input::ihex::Ihex;
+use crate::input::raw::Raw;
+
+pub struct ImageSymbol {
+    pub name: String,
+    pub address: u32,
+    pub is_global: bool,
+}
+
+pub struct Image {
+    pub text_address: u32,
+    pub text: Vec<u32>,
+    pub data_address: u32,
+    pub data: Vec<u32>,
+    pub symbols: Vec<ImageSymbol>,
+    pub entry: Option<u32>,
+    pub delay_slots: bool,
+}
+
+impl Image {
+    pub fn new(text: Vec<u32>, data: Vec<u32>) -> Self {
+        Self {
+            text_address: TEXT_SECTION_MIN_ADDRESS as u32,
+            text,
+            data_address: DATA_SECTION_MIN_ADDRESS as u32,
+            data,
+            symbols: vec![],
+            entry: None,
+            delay_slots: false,
+        }
+    }
+}
+
+pub trait Input {
+    fn read(&self, bytes: &[u8], endianness: Endianness) -> Image;
+}
+
+pub fn resolve_input_format(name: &str) -> Option<Box<dyn Input>> {
+    match name {
+        "bits" => Some(Box::new(Bits)),
+        "bin" | "binary" => Some(Box::new(Bin)),
+        "raw" => Some(Box::new(Raw)),
+        "hex" | "readmemh" => Some(Box::new(Hex)),
+        "ihex" => Some(Box::new(Ihex)),
+        "elf" | "elf-exec" => Some(Box::new(Elf)),
+        _ => None,
+    }
+}
+
+pub fn detect_input_format(bytes: &[u8]) -> &'static str {
+    let is_text_of = |is_valid: fn(&u8) -> bool| {
+        !bytes.is_empty()
+            && bytes
+                .iter()
+                .all(|byte| byte.is_ascii_whitespace() || is_valid(byte))
+    };
+
+    if bytes.starts_with(b"\x7fELF") {
+        "elf"
+    } else if bytes.starts_with(b"MIPS") {
+        "bin"
+    } else if bytes.starts_with(b":") {
+        "ihex"
+    } else if is_text_of(|byte| *byte == b'0' || *byte == b'1') {
+        "bits"
+    } else if is_text_of(u8::is_ascii_hexdigit) {
+        "hex"
+    } else {
+        "raw"
+    }
+}
+
+pub fn convert_bytes_to_words(bytes: &[u8], endianness: Endianness) -> Vec<u32> {
+    if !bytes.len().is_multiple_of(4) {
+        panic!("Section size is not a multiple of the word size.");
+    }
+    bytes
+        .chunks(4)
+        .map(|chunk| endianness.bytes_to_word(chunk))
+        .collect()
+}
+
+pub fn read_image(bytes: &[u8], format: Option<&str>, endianness: Endianness) -> Image {
+    let format = format.unwrap_or_else(|| detect_input_format(bytes));
+    resolve_input_format(format)
+        .expect("Unknown input format.")
+        .read(bytes, endianness)
+}
@@ -0,0 +1,27 @@
+use crate::endianness::Endianness;
+use crate::input::{convert_bytes_to_words, Image, Input};
+
+const HEADER_SIZE: usize = 16;
+
+pub struct Bin;
+
+impl Input for Bin {
+    fn read(&self, bytes: &[u8], _endianness: Endianness) -> Image {
+        if bytes.len() < HEADER_SIZE || !bytes.starts_with(b"MIPS") {
+            panic!("Invalid binary header.");
+        }
+        let endianness = Endianness::from_ident(bytes[4]).expect("Invalid byte order.");
+        let text_size = endianness.bytes_to_word(&bytes[8..12]) as usize;
+        let data_size = endianness.bytes_to_word(&bytes[12..16]) as usize;
+        if bytes.len() != HEADER_SIZE + text_size + data_size {
+            panic!("Section sizes do not match the binary.");
+        }
+
+        let text = &bytes[HEADER_SIZE..HEADER_SIZE + text_size];
+        let data = &bytes[HEADER_SIZE + text_size..];
+        Image::new(
+            convert_bytes_to_words(text, endianness),
+            convert_bytes_to_words(data, endianness),
+        )
+    }
+}
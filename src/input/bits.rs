@@ -0,0 +1,33 @@
+use crate::endianness::Endianness;
+use crate::input::{Image, Input};
+
+pub struct Bits;
+
+impl Input for Bits {
+    fn read(&self, bytes: &[u8], _endianness: Endianness) -> Image {
+        let bits = bytes
+            .iter()
+            .filter(|byte| !byte.is_ascii_whitespace())
+            .map(|byte| *byte as char)
+            .collect::<String>();
+        let words = bits
+            .as_bytes()
+            .chunks(32)
+            .map(|chunk| {
+                let chunk = std::str::from_utf8(chunk).unwrap();
+                u32::from_str_radix(chunk, 2).expect("Invalid bit string.")
+            })
+            .collect::<Vec<u32>>();
+
+        if let [text_size, data_size, ref words @ ..] = words[..] {
+            let text_count = text_size as usize / 4;
+            let data_count = data_size as usize / 4;
+            if words.len() != text_count + data_count {
+                panic!("Section sizes do not match the bit string.");
+            }
+            Image::new(words[..text_count].to_vec(), words[text_count..].to_vec())
+        } else {
+            panic!("Bit string is too short.");
+        }
+    }
+}
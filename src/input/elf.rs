@@ -0,0 +1,190 @@
+use crate::constants::{DATA_SECTION_MIN_ADDRESS, TEXT_SECTION_MIN_ADDRESS};
+use crate::endianness::Endianness;
+use crate::input::{convert_bytes_to_words, Image, ImageSymbol, Input};
+
+const ELF_HEADER_SIZE: usize = 52;
+const SYMBOL_SIZE: usize = 16;
+
+const ET_REL: u16 = 1;
+const ET_EXEC: u16 = 2;
+const EF_MIPS_NOREORDER: u32 = 0x1;
+
+const SHT_SYMTAB: u32 = 2;
+const SHT_NOBITS: u32 = 8;
+
+const STB_GLOBAL: u8 = 1;
+const STT_SECTION: u8 = 3;
+const STT_FILE: u8 = 4;
+
+const SHN_UNDEF: u16 = 0;
+const SHN_LORESERVE: u16 = 0xff00;
+
+pub struct Elf;
+
+struct SectionHeader {
+    name: String,
+    section_type: u32,
+    address: u32,
+    offset: usize,
+    size: usize,
+    link: usize,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    endianness: Endianness,
+}
+
+impl Reader<'_> {
+    fn slice(&self, offset: usize, size: usize) -> &[u8] {
+        self.bytes
+            .get(offset..offset + size)
+            .expect("Unexpected end of ELF file.")
+    }
+
+    fn half(&self, offset: usize) -> u16 {
+        self.endianness.bytes_to_half(self.slice(offset, 2))
+    }
+
+    fn word(&self, offset: usize) -> u32 {
+        self.endianness.bytes_to_word(self.slice(offset, 4))
+    }
+
+    fn string(&self, offset: usize) -> String {
+        let bytes = &self.bytes[offset.min(self.bytes.len())..];
+        let end = bytes
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(bytes.len());
+        String::from_utf8_lossy(&bytes[..end]).into_owned()
+    }
+}
+
+fn read_section_headers(reader: &Reader) -> Vec<SectionHeader> {
+    let section_header_offset = reader.word(32) as usize;
+    let section_header_size = reader.half(46) as usize;
+    let section_count = reader.half(48) as usize;
+    let string_table_index = reader.half(50) as usize;
+
+    let headers = (0..section_count)
+        .map(|index| {
+            let offset = section_header_offset + index * section_header_size;
+            (
+                reader.word(offset) as usize,
+                SectionHeader {
+                    name: String::new(),
+                    section_type: reader.word(offset + 4),
+                    address: reader.word(offset + 12),
+                    offset: reader.word(offset + 16) as usize,
+                    size: reader.word(offset + 20) as usize,
+                    link: reader.word(offset + 24) as usize,
+                },
+            )
+        })
+        .collect::<Vec<(usize, SectionHeader)>>();
+
+    let string_table_offset = headers
+        .get(string_table_index)
+        .map_or(0, |(_, header)| header.offset);
+    headers
+        .into_iter()
+        .map(|(name_offset, header)| SectionHeader {
+            name: reader.string(string_table_offset + name_offset),
+            ..header
+        })
+        .collect()
+}
+
+impl Input for Elf {
+    fn read(&self, bytes: &[u8], _endianness: Endianness) -> Image {
+        if bytes.len() < ELF_HEADER_SIZE || !bytes.starts_with(b"\x7fELF") || bytes[4] != 1 {
+            panic!("Not an ELF32 file.");
+        }
+        let reader = Reader {
+            bytes,
+            endianness: Endianness::from_ident(bytes[5]).expect("Invalid byte order."),
+        };
+        let elf_type = reader.half(16);
+        let headers = read_section_headers(&reader);
+
+        let find_section = |name: &str| headers.iter().position(|header| header.name == name);
+        let section_base = |index: usize, default_address: i32| {
+            if elf_type == ET_REL {
+                default_address as u32
+            } else {
+                headers[index].address
+            }
+        };
+        let section_words = |index: Option<usize>| {
+            index
+                .filter(|index| headers[*index].section_type != SHT_NOBITS)
+                .map_or_else(Vec::new, |index| {
+                    let header = &headers[index];
+                    convert_bytes_to_words(
+                        reader.slice(header.offset, header.size),
+                        reader.endianness,
+                    )
+                })
+        };
+
+        let text_index = find_section(".text");
+        let data_index = find_section(".data");
+        let text_address = text_index.map_or(TEXT_SECTION_MIN_ADDRESS as u32, |index| {
+            section_base(index, TEXT_SECTION_MIN_ADDRESS)
+        });
+        let data_address = data_index.map_or(DATA_SECTION_MIN_ADDRESS as u32, |index| {
+            section_base(index, DATA_SECTION_MIN_ADDRESS)
+        });
+
+        let reader = &reader;
+        let symbols = headers
+            .iter()
+            .filter(|header| header.section_type == SHT_SYMTAB)
+            .flat_map(|header| {
+                let string_table_offset = headers[header.link].offset;
+                (0..header.size / SYMBOL_SIZE).filter_map(move |index| {
+                    let offset = header.offset + index * SYMBOL_SIZE;
+                    let name = reader.string(string_table_offset + reader.word(offset) as usize);
+                    let value = reader.word(offset + 4);
+                    let info = reader.slice(offset + 12, 1)[0];
+                    let section_index = reader.half(offset + 14);
+                    let symbol_type = info & 0xf;
+                    if name.is_empty()
+                        || symbol_type == STT_SECTION
+                        || symbol_type == STT_FILE
+                        || section_index == SHN_UNDEF
+                        || section_index >= SHN_LORESERVE
+                    {
+                        return None;
+                    }
+                    let address = if elf_type == ET_REL {
+                        let section_index = Some(section_index as usize);
+                        if section_index == text_index {
+                            value + text_address
+                        } else if section_index == data_index {
+                            value + data_address
+                        } else {
+                            return None;
+                        }
+                    } else {
+                        value
+                    };
+                    Some(ImageSymbol {
+                        name,
+                        address,
+                        is_global: info >> 4 == STB_GLOBAL,
+                    })
+                })
+            })
+            .collect();
+
+        Image {
+            text_address,
+            data_address,
+            symbols,
+            entry: (elf_type == ET_EXEC).then(|| reader.word(24)),
+            delay_slots: reader.word(36) & EF_MIPS_NOREORDER != 0,
+            ..Image::new(section_words(text_index), section_words(data_index))
+        }
+    }
+}
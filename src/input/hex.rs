@@ -0,0 +1,19 @@
+use crate::endianness::Endianness;
+use crate::input::{Image, Input};
+
+pub struct Hex;
+
+impl Input for Hex {
+    fn read(&self, bytes: &[u8], _endianness: Endianness) -> Image {
+        let text = String::from_utf8_lossy(bytes);
+        let words = text
+            .lines()
+            .map(|line| line.split("//").next().unwrap().trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('@'))
+            .flat_map(|line| line.split_whitespace())
+            .map(|word| u32::from_str_radix(word, 16).expect("Invalid hex word."))
+            .collect();
+
+        Image::new(words, vec![])
+    }
+}
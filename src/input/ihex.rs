@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+
+use crate::constants::{DATA_SECTION_MIN_ADDRESS, TEXT_SECTION_MIN_ADDRESS};
+use crate::endianness::Endianness;
+use crate::input::{convert_bytes_to_words, Image, Input};
+
+const DATA_RECORD: u8 = 0x00;
+const END_OF_FILE_RECORD: u8 = 0x01;
+const EXTENDED_LINEAR_ADDRESS_RECORD: u8 = 0x04;
+const START_LINEAR_ADDRESS_RECORD: u8 = 0x05;
+
+pub struct Ihex;
+
+fn parse_record(line: &str) -> Vec<u8> {
+    let digits = line.strip_prefix(':').expect("Invalid Intel HEX record.");
+    let bytes = (0..digits.len())
+        .step_by(2)
+        .map(|index| {
+            digits
+                .get(index..index + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .expect("Invalid Intel HEX record.")
+        })
+        .collect::<Vec<u8>>();
+    let checksum = bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    if bytes.len() < 5 || bytes.len() != bytes[0] as usize + 5 || checksum != 0 {
+        panic!("Invalid Intel HEX record.");
+    }
+    bytes
+}
+
+fn collect_section(memory: &BTreeMap<u32, u8>, start: u32, end: u32) -> (u32, Vec<u8>) {
+    let bytes = memory
+        .range(start..end)
+        .map(|(address, byte)| (*address, *byte))
+        .collect::<Vec<(u32, u8)>>();
+    let address = bytes.first().map_or(start, |(address, _)| *address);
+    if bytes
+        .iter()
+        .enumerate()
+        .any(|(index, (byte_address, _))| *byte_address != address + index as u32)
+    {
+        panic!("Intel HEX section is not contiguous.");
+    }
+    (address, bytes.iter().map(|(_, byte)| *byte).collect())
+}
+
+impl Input for Ihex {
+    fn read(&self, bytes: &[u8], endianness: Endianness) -> Image {
+        let mut memory: BTreeMap<u32, u8> = BTreeMap::new();
+        let mut upper_address = 0u32;
+        let mut entry = None;
+
+        for line in String::from_utf8_lossy(bytes).lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let record = parse_record(line);
+            let address = u16::from_be_bytes([record[1], record[2]]) as u32;
+            let payload = &record[4..record.len() - 1];
+            match record[3] {
+                DATA_RECORD => payload.iter().enumerate().for_each(|(index, byte)| {
+                    memory.insert(upper_address + address + index as u32, *byte);
+                }),
+                END_OF_FILE_RECORD => break,
+                EXTENDED_LINEAR_ADDRESS_RECORD => {
+                    upper_address = (u16::from_be_bytes([payload[0], payload[1]]) as u32) << 16
+                }
+                START_LINEAR_ADDRESS_RECORD => {
+                    entry = Some(u32::from_be_bytes(payload.try_into().unwrap()))
+                }
+                _ => {}
+            }
+        }
+
+        let (text_address, text) = collect_section(
+            &memory,
+            TEXT_SECTION_MIN_ADDRESS as u32,
+            DATA_SECTION_MIN_ADDRESS as u32,
+        );
+        let (data_address, data) =
+            collect_section(&memory, DATA_SECTION_MIN_ADDRESS as u32, u32::MAX);
+
+        Image {
+            text_address,
+            data_address,
+            entry,
+            ..Image::new(
+                convert_bytes_to_words(&text, endianness),
+                convert_bytes_to_words(&data, endianness),
+            )
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::endianness::Endianness;
+use crate::input::{convert_bytes_to_words, Image, Input};
+
+pub struct Raw;
+
+impl Input for Raw {
+    fn read(&self, bytes: &[u8], endianness: Endianness) -> Image {
+        Image::new(convert_bytes_to_words(bytes, endianness), vec![])
+    }
+}
@@ -1,5 +1,11 @@
+use crate::constants::INSTRUCTION_TABLE;
 use crate::text::Text;
 
+const COPROCESSOR1_OPCODE: i32 = 0x11;
+const BRANCH_FORMAT_CODE: i32 = 0x8;
+const DOUBLE_FORMAT_CODE: i32 = 0x11;
+
+#[derive(Clone, Copy, PartialEq)]
 pub enum InstructionFormat {
     REGISTER,
     IMMEDIATE,
@@ -10,6 +16,7 @@ pub enum InstructionFormat {
 pub struct Instruction {
     pub name: &'static str,
     pub opcode: i32,
+    pub format_code: i32,
     pub funct: i32,
 }
 
@@ -18,20 +25,119 @@ impl Instruction {
         Self {
             name,
             opcode,
+            format_code: -1,
             funct,
         }
     }
 
+    pub const fn new_coprocessor(
+        name: &'static str,
+        opcode: i32,
+        format_code: i32,
+        funct: i32,
+    ) -> Self {
+        Self {
+            name,
+            opcode,
+            format_code,
+            funct,
+        }
+    }
+
+    pub fn is_coprocessor(&self) -> bool {
+        self.format_code >= 0
+    }
+
+    pub fn is_float(&self) -> bool {
+        self.opcode == COPROCESSOR1_OPCODE || self.is_float_load_store()
+    }
+
+    pub fn is_double(&self) -> bool {
+        (self.opcode == COPROCESSOR1_OPCODE && self.format_code == DOUBLE_FORMAT_CODE)
+            || self.opcode == 0x35
+            || self.opcode == 0x3d
+    }
+
+    pub fn is_double_result(&self) -> bool {
+        match self.funct {
+            0x20 | 0x24 => false,
+            0x21 => true,
+            _ => self.is_double(),
+        }
+    }
+
+    pub fn is_float_load_store(&self) -> bool {
+        matches!(self.opcode, 0x31 | 0x35 | 0x39 | 0x3d)
+    }
+
+    pub fn is_float_operation(&self) -> bool {
+        self.opcode == COPROCESSOR1_OPCODE && self.format_code >= 0x10
+    }
+
+    pub fn is_float_operand(&self, index: usize) -> bool {
+        self.is_float_operation()
+            || (self.is_float_load_store() && index == 0)
+            || (self.opcode == COPROCESSOR1_OPCODE && index == 1)
+    }
+
+    pub fn is_float_compare(&self) -> bool {
+        self.is_float_operation() && self.funct >= 0x30
+    }
+
+    pub fn is_coprocessor_branch(&self) -> bool {
+        self.opcode == COPROCESSOR1_OPCODE && self.format_code == BRANCH_FORMAT_CODE
+    }
+
     pub fn is_branch(&self) -> bool {
-        self.opcode == 4 || self.opcode == 5
+        (4..8).contains(&self.opcode) || self.is_coprocessor_branch()
+    }
+
+    pub fn is_branch_zero(&self) -> bool {
+        self.opcode == 6 || self.opcode == 7
+    }
+
+    pub fn is_load_store(&self) -> bool {
+        (0x20..0x30).contains(&self.opcode) || self.is_float_load_store()
+    }
+
+    pub fn is_load(&self) -> bool {
+        (0x20..0x28).contains(&self.opcode) || self.opcode == 0x31 || self.opcode == 0x35
+    }
+
+    pub fn is_zero_extended(&self) -> bool {
+        (0xc..0x10).contains(&self.opcode)
     }
 
     pub fn is_shift(&self) -> bool {
-        self.funct == 0 || self.funct == 2
+        self.opcode == 0 && (0..4).contains(&self.funct)
+    }
+
+    pub fn is_variable_shift(&self) -> bool {
+        self.opcode == 0 && (4..8).contains(&self.funct)
     }
 
     pub fn is_register_jump(&self) -> bool {
-        self.funct == 8
+        self.opcode == 0 && (self.funct == 8 || self.funct == 9)
+    }
+
+    pub fn is_control(&self) -> bool {
+        self.is_branch() || self.opcode == 2 || self.opcode == 3 || self.is_register_jump()
+    }
+
+    pub fn is_move_from_hi_lo(&self) -> bool {
+        self.opcode == 0 && (self.funct == 0x10 || self.funct == 0x12)
+    }
+
+    pub fn is_move_to_hi_lo(&self) -> bool {
+        self.opcode == 0 && (self.funct == 0x11 || self.funct == 0x13)
+    }
+
+    pub fn is_multiply_divide(&self) -> bool {
+        self.opcode == 0 && (0x18..0x1c).contains(&self.funct)
+    }
+
+    pub fn is_trap(&self) -> bool {
+        self.opcode == 0 && (self.funct == 0xc || self.funct == 0xd)
     }
 
     pub fn to_register_format_text(&self, rs: i32, rt: i32, rd: i32, shamt: i32) -> Text {
@@ -47,11 +153,25 @@ impl Instruction {
     }
 }
 
-pub fn convert_opcode_to_format(opcode: i32) -> InstructionFormat {
-    match opcode {
-        0 => InstructionFormat::REGISTER,
-        2 | 3 => InstructionFormat::JUMP,
-        -1 => InstructionFormat::PSEUDO,
+pub fn convert_opcode_to_format(opcode: i32, format_code: i32) -> InstructionFormat {
+    match (opcode, format_code) {
+        (COPROCESSOR1_OPCODE, BRANCH_FORMAT_CODE) => InstructionFormat::IMMEDIATE,
+        (0 | 0x10 | COPROCESSOR1_OPCODE, _) => InstructionFormat::REGISTER,
+        (2 | 3, _) => InstructionFormat::JUMP,
+        (-1, _) => InstructionFormat::PSEUDO,
         _ => InstructionFormat::IMMEDIATE,
     }
 }
+
+pub fn find_instruction_by_encoding(text: &Text) -> Option<&'static Instruction> {
+    let format = convert_opcode_to_format(text.opcode, text.rs);
+    INSTRUCTION_TABLE.values().copied().find(|instruction| {
+        instruction.opcode == text.opcode
+            && (!instruction.is_coprocessor() || instruction.format_code == text.rs)
+            && match format {
+                InstructionFormat::REGISTER => instruction.funct == text.funct,
+                _ if instruction.is_coprocessor_branch() => instruction.funct == text.rt,
+                _ => true,
+            }
+    })
+}
@@ -1,12 +1,15 @@
 use regex::Regex;
+use std::sync::OnceLock;
 
-use crate::constants::{TEXT_SECTION_MIN_ADDRESS, WORD};
+use crate::constants::WORD;
 use crate::line::Line;
 use crate::section::Section;
+use crate::source::Source;
 
 pub struct Label {
-    name: String,
+    pub name: String,
     pub address: i32,
+    pub source: Option<Source>,
 }
 
 impl Label {
@@ -14,6 +17,7 @@ impl Label {
         Self {
             name: name.to_string(),
             address,
+            source: None,
         }
     }
 }
@@ -22,14 +26,17 @@ pub fn find_label<'a>(name: &'a str, labels: &'a [Label]) -> Option<&'a Label> {
     labels.iter().find(|label| label.name == name)
 }
 
+fn label_regex() -> &'static Regex {
+    static LABEL_REGEX: OnceLock<Regex> = OnceLock::new();
+    LABEL_REGEX.get_or_init(|| Regex::new(r"^.*:").unwrap())
+}
+
 pub fn is_label(code: &str) -> bool {
-    let label_regex = Regex::new(r"^.*:").unwrap();
-    label_regex.is_match(code)
+    label_regex().is_match(code)
 }
 
 pub fn resolve_labels(code: &str) -> Option<Label> {
-    let label_regex = Regex::new(r"^.*:").unwrap();
-    if let Some(cap) = label_regex.captures_iter(&code).next() {
+    if let Some(cap) = label_regex().captures_iter(code).next() {
         let name = cap[0].trim_end_matches(':');
         Some(Label::new(name, 0))
     } else {
@@ -37,16 +44,19 @@ pub fn resolve_labels(code: &str) -> Option<Label> {
     }
 }
 
-pub fn get_addressed_labels(lines: &[Line], codes: &[String]) -> Vec<Label> {
-    let mut current_address = TEXT_SECTION_MIN_ADDRESS;
+pub fn get_addressed_labels(lines: &[Line], codes: &[Source], start_address: i32) -> Vec<Label> {
+    let mut current_address = start_address;
     let labels = extract_labels_from_lines(lines);
 
     codes
         .iter()
         .filter_map(|code| {
-            if let Some(label) = resolve_labels(&code) {
+            if let Some(label) = resolve_labels(&code.code) {
                 if let Some(label) = find_label(&label.name, &labels) {
-                    Some(Label::new(&label.name, current_address))
+                    Some(Label {
+                        source: Some(code.clone()),
+                        ..Label::new(&label.name, current_address)
+                    })
                 } else {
                     panic!("Use of undeclared label.");
                 }
@@ -61,14 +71,7 @@ pub fn get_addressed_labels(lines: &[Line], codes: &[String]) -> Vec<Label> {
 fn extract_labels_from_lines(lines: &[Line]) -> Vec<Label> {
     lines
         .iter()
-        .filter(|line| line.section == Section::TEXT)
-        .map(|line| {
-            if let Some(label) = resolve_labels(&line.text.as_ref().unwrap()) {
-                Some(label)
-            } else {
-                None
-            }
-        })
-        .filter_map(|label| label)
+        .filter(|line| line.section == Section::TEXT || line.section == Section::KTEXT)
+        .filter_map(|line| resolve_labels(line.text.as_ref().unwrap()))
         .collect()
 }
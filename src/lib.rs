@@ -0,0 +1,260 @@
+#![allow(clippy::upper_case_acronyms)]
+
+use std::fs::File;
+
+pub mod constants;
+pub mod datum;
+pub mod debugger;
+pub mod delay_slot;
+pub mod directive;
+pub mod disassembler;
+pub mod endianness;
+pub mod gdb_server;
+pub mod hazard;
+pub mod input;
+pub mod instruction;
+pub mod label;
+pub mod line;
+pub mod link_map;
+pub mod listing;
+pub mod options;
+pub mod output;
+pub mod pseudo_instruction;
+pub mod register;
+pub mod relocation;
+pub mod section;
+pub mod simulator;
+pub mod source;
+pub mod symbol;
+pub mod text;
+pub mod utils;
+pub mod warning;
+
+use crate::constants::{KERNEL_TEXT_SECTION_MIN_ADDRESS, TEXT_SECTION_MIN_ADDRESS, WORD};
+use crate::datum::{extract_data_from_lines, find_datum, resolve_data_symbols, Datum};
+use crate::delay_slot::fill_delay_slots;
+use crate::directive::{extract_globals, extract_reorder_modes};
+use crate::hazard::insert_nops;
+use crate::label::{find_label, get_addressed_labels, is_label, resolve_labels, Label};
+use crate::line::{compose_lines, Line};
+use crate::pseudo_instruction::disassemble_pseudo_instruction;
+use crate::section::{resolve_section, Section};
+use crate::source::Source;
+use crate::text::{get_text_from_code, Text};
+use crate::warning::{collect_warnings, Warning};
+
+pub struct Program {
+    pub data: Vec<Datum>,
+    pub labels: Vec<Label>,
+    pub texts: Vec<Text>,
+    pub kernel_texts: Vec<Text>,
+    pub globals: Vec<String>,
+    pub warnings: Vec<Warning>,
+    pub delay_slots: bool,
+}
+
+impl Program {
+    pub fn is_global(&self, name: &str) -> bool {
+        self.globals.iter().any(|global| global == name)
+    }
+
+    pub fn has_kernel_sections(&self) -> bool {
+        !self.kernel_texts.is_empty() || self.data.iter().any(Datum::is_kernel)
+    }
+
+    pub fn has_floating_point(&self) -> bool {
+        self.texts.iter().chain(&self.kernel_texts).any(|text| {
+            text.get_instruction()
+                .is_some_and(|instruction| instruction.is_float())
+        })
+    }
+
+    pub fn get_externs(&self) -> Vec<String> {
+        self.globals
+            .iter()
+            .filter(|global| {
+                find_datum(global, &self.data).is_none()
+                    && find_label(global, &self.labels).is_none()
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_entry_address(&self, entry: Option<&str>) -> u32 {
+        let entry = match entry {
+            Some(name) => Some(find_label(name, &self.labels).expect("Entry symbol not found.")),
+            None => {
+                find_label("__start", &self.labels).or_else(|| find_label("main", &self.labels))
+            }
+        };
+
+        entry.map_or(TEXT_SECTION_MIN_ADDRESS, |label| label.address) as u32
+    }
+}
+
+pub fn assemble(input_file: &mut File, relocatable: bool, delay_slots: bool) -> Program {
+    let lines = compose_lines(input_file);
+    assemble_lines(&lines, relocatable, delay_slots).2
+}
+
+pub fn assemble_with_nops(input_file: &mut File, relocatable: bool, delay_slots: bool) -> Program {
+    let lines = compose_lines(input_file);
+    let (codes, kernel_codes, program) = assemble_lines(&lines, relocatable, delay_slots);
+
+    let codes = insert_nops(&codes, &program.texts, delay_slots);
+    compose_program(
+        &lines,
+        extract_data_from_lines(&lines),
+        &codes,
+        &kernel_codes,
+        relocatable,
+        delay_slots,
+    )
+}
+
+fn assemble_lines(
+    lines: &[Line],
+    relocatable: bool,
+    delay_slots: bool,
+) -> (Vec<Source>, Vec<Source>, Program) {
+    let data = extract_data_from_lines(lines);
+    let codes = extract_codes(lines, &data, relocatable, Section::TEXT);
+    let kernel_codes = extract_codes(lines, &data, relocatable, Section::KTEXT);
+    let program = compose_program(lines, data, &codes, &kernel_codes, relocatable, delay_slots);
+    if !delay_slots {
+        return (codes, kernel_codes, program);
+    }
+
+    let modes = extract_reorder_modes(lines);
+    let codes = fill_delay_slots(&codes, &program.texts, &modes);
+    let kernel_codes = fill_delay_slots(&kernel_codes, &program.kernel_texts, &modes);
+    let program = compose_program(
+        lines,
+        extract_data_from_lines(lines),
+        &codes,
+        &kernel_codes,
+        relocatable,
+        delay_slots,
+    );
+    (codes, kernel_codes, program)
+}
+
+fn compose_program(
+    lines: &[Line],
+    data: Vec<Datum>,
+    codes: &[Source],
+    kernel_codes: &[Source],
+    relocatable: bool,
+    delay_slots: bool,
+) -> Program {
+    let mut labels = get_addressed_labels(lines, codes, TEXT_SECTION_MIN_ADDRESS);
+    labels.extend(get_addressed_labels(
+        lines,
+        kernel_codes,
+        KERNEL_TEXT_SECTION_MIN_ADDRESS,
+    ));
+
+    let mut program = Program {
+        data,
+        labels,
+        texts: vec![],
+        kernel_texts: vec![],
+        globals: extract_globals(lines),
+        warnings: vec![],
+        delay_slots,
+    };
+
+    let externs = program.get_externs();
+    if let Some(name) = externs.first().filter(|_| !relocatable) {
+        panic!("Undefined symbol: {}", name);
+    }
+    resolve_data_symbols(&mut program.data, &program.labels, &externs);
+    program.texts = disassemble_instructions(
+        &program.data,
+        &program.labels,
+        &externs,
+        codes,
+        TEXT_SECTION_MIN_ADDRESS,
+    );
+    program.kernel_texts = disassemble_instructions(
+        &program.data,
+        &program.labels,
+        &externs,
+        kernel_codes,
+        KERNEL_TEXT_SECTION_MIN_ADDRESS,
+    );
+    program.warnings = collect_warnings(&program.texts);
+    program
+        .warnings
+        .extend(collect_warnings(&program.kernel_texts));
+    if !delay_slots {
+        program
+            .warnings
+            .extend(extract_reorder_modes(lines).iter().map(|(line_number, _)| {
+                Warning::new(
+                    Some(*line_number),
+                    ".set reorder and .set noreorder have no effect without --delay-slots.",
+                )
+            }));
+    }
+
+    program
+}
+
+fn extract_codes(
+    lines: &[Line],
+    data: &[Datum],
+    relocatable: bool,
+    section: Section,
+) -> Vec<Source> {
+    lines
+        .iter()
+        .filter(|line| {
+            line.section == section && resolve_section(line.text.as_ref().unwrap()).is_none()
+        })
+        .flat_map(|line| {
+            let text = line.text.as_ref().unwrap();
+            if !is_label(text) {
+                if let Some(pseudo_instruction_codes) =
+                    disassemble_pseudo_instruction(text, data, relocatable)
+                {
+                    let pseudo_instruction = Source::new(line.number, text, text);
+                    pseudo_instruction_codes
+                        .iter()
+                        .map(|code| Source {
+                            pseudo_instruction: Some(pseudo_instruction.get_mnemonic().to_string()),
+                            ..Source::new(line.number, text, code)
+                        })
+                        .collect()
+                } else {
+                    vec![Source::new(line.number, text, text.trim_start())]
+                }
+            } else {
+                vec![Source::new(line.number, text, text)]
+            }
+        })
+        .collect()
+}
+
+fn disassemble_instructions(
+    data: &[Datum],
+    labels: &[Label],
+    externs: &[String],
+    codes: &[Source],
+    start_address: i32,
+) -> Vec<Text> {
+    let mut current_address = start_address;
+    codes
+        .iter()
+        .filter_map(|code| {
+            if resolve_labels(&code.code).is_none() {
+                let text = get_text_from_code(&code.code, current_address, data, labels, externs)
+                    .with_source(code);
+                current_address += WORD;
+                Some(text)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
@@ -1,17 +1,21 @@
-use crate::constants::{DATA_SECTION_MIN_ADDRESS, WORD};
+use crate::constants::{DATA_SECTION_MIN_ADDRESS, KERNEL_DATA_SECTION_MIN_ADDRESS, WORD};
+use crate::datum::get_data_size;
+use crate::directive::resolve_directive;
 use crate::section::{resolve_section, Section};
 use crate::utils::read_lines;
 use std::fs::File;
 
 pub struct Line {
+    pub number: usize,
     pub section: Section,
     pub address: i32,
     pub text: Option<String>,
 }
 
 impl Line {
-    fn new(section: Section, address: i32, text: Option<String>) -> Self {
+    fn new(number: usize, section: Section, address: i32, text: Option<String>) -> Self {
         Self {
+            number,
             section,
             address,
             text,
@@ -19,28 +23,64 @@ impl Line {
     }
 }
 
-pub fn compose_lines(mut input_file: &mut File) -> Vec<Line> {
-    let lines = read_lines(&mut input_file);
+pub fn compose_lines(input_file: &mut File) -> Vec<Line> {
+    let lines = read_lines(input_file);
 
     let mut current_address = DATA_SECTION_MIN_ADDRESS - WORD;
+    let mut kernel_address = KERNEL_DATA_SECTION_MIN_ADDRESS - WORD;
     let mut current_section = Section::NONE;
 
     lines
-        .map(|line| {
+        .enumerate()
+        .map(|(index, line)| {
+            let number = index + 1;
+            let line = strip_comment(&line);
+            if line.trim().is_empty() {
+                return Line::new(number, Section::NONE, current_address, None);
+            }
+
             current_section = resolve_section(&line).unwrap_or_else(|| current_section.clone());
+            if resolve_directive(&line).is_some() {
+                return Line::new(number, Section::NONE, current_address, Some(line));
+            }
+
             match current_section {
                 Section::DATA => {
                     let result = if resolve_section(&line).is_none() {
-                        Line::new(Section::DATA, current_address, Some(line))
+                        Line::new(number, Section::DATA, current_address, Some(line))
                     } else {
-                        Line::new(Section::NONE, current_address, None)
+                        Line::new(number, Section::NONE, current_address, None)
                     };
-                    current_address += WORD;
+                    current_address += get_line_size(&result);
                     result
                 }
-                Section::TEXT => Line::new(Section::TEXT, current_address, Some(line)),
-                Section::NONE => Line::new(Section::NONE, current_address, None),
+                Section::KDATA => {
+                    let result = if resolve_section(&line).is_none() {
+                        Line::new(number, Section::KDATA, kernel_address, Some(line))
+                    } else {
+                        Line::new(number, Section::NONE, kernel_address, None)
+                    };
+                    kernel_address += get_line_size(&result);
+                    result
+                }
+                Section::TEXT => Line::new(number, Section::TEXT, current_address, Some(line)),
+                Section::KTEXT => Line::new(number, Section::KTEXT, current_address, Some(line)),
+                Section::NONE => Line::new(number, Section::NONE, current_address, None),
             }
         })
         .collect::<Vec<Line>>()
 }
+
+fn get_line_size(line: &Line) -> i32 {
+    line.text
+        .as_ref()
+        .map_or(WORD, |text| get_data_size(text, line.address))
+}
+
+fn strip_comment(line: &str) -> String {
+    line.split('#')
+        .next()
+        .unwrap_or_default()
+        .trim_end()
+        .to_string()
+}
@@ -0,0 +1,62 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::constants::{DATA_SECTION_MIN_ADDRESS, TEXT_SECTION_MIN_ADDRESS};
+use crate::symbol::{collect_symbols, get_data_end_address, get_text_end_address};
+use crate::Program;
+
+fn compose_section_row(name: &str, start_address: i32, end_address: i32) -> String {
+    format!(
+        "{:<8} {:08x}  {:08x}  {:08x}\n",
+        name,
+        start_address,
+        end_address,
+        end_address - start_address
+    )
+}
+
+pub fn write_link_map(program: &Program, input_filepath: &str, filepath: &str) {
+    let mut result = String::from("Sections\nName     Start     End       Size\n");
+    result.push_str(&compose_section_row(
+        ".text",
+        TEXT_SECTION_MIN_ADDRESS,
+        get_text_end_address(program),
+    ));
+    result.push_str(&compose_section_row(
+        ".data",
+        DATA_SECTION_MIN_ADDRESS,
+        get_data_end_address(program).unwrap_or(DATA_SECTION_MIN_ADDRESS),
+    ));
+
+    let symbols = collect_symbols(program);
+    let name_width = symbols
+        .iter()
+        .map(|symbol| symbol.name.len())
+        .max()
+        .unwrap_or_default()
+        .max(4);
+    result.push_str(&format!(
+        "\nSymbols\nAddress   Size      Section  Binding  {:<width$}  Source\n",
+        "Name",
+        width = name_width
+    ));
+    symbols.iter().for_each(|symbol| {
+        let location = symbol.line_number.map_or_else(String::new, |line_number| {
+            format!("{}:{}", input_filepath, line_number)
+        });
+        let row = format!(
+            "{:08x}  {:08x}  {:<7}  {:<7}  {:<width$}  {}",
+            symbol.address,
+            symbol.size,
+            symbol.section.to_name(),
+            symbol.binding.to_name(),
+            symbol.name,
+            location,
+            width = name_width
+        );
+        result.push_str(&format!("{}\n", row.trim_end()));
+    });
+
+    let mut file = File::create(filepath).expect("Failed to create map file.");
+    write!(file, "{}", result).expect("Failed to write map file.");
+}
@@ -0,0 +1,109 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::constants::{TEXT_SECTION_MIN_ADDRESS, WORD};
+use crate::endianness::Endianness;
+use crate::section::Section;
+use crate::source::{format_source, Source};
+use crate::symbol::collect_symbols;
+use crate::Program;
+
+const SOURCE_WIDTH: usize = 32;
+
+fn compose_row(address: i32, word: Option<u32>, source: &Option<Source>) -> String {
+    let word = word.map_or_else(|| " ".repeat(8), |word| format!("{:08x}", word));
+    let (line_number, line) = source.as_ref().map_or_else(
+        || (String::new(), String::new()),
+        |source| {
+            let line = format_source(&source.line);
+            let line = match &source.pseudo_instruction {
+                Some(pseudo_instruction) => format!(
+                    "{:<width$}; {} -> {}",
+                    line,
+                    pseudo_instruction,
+                    format_source(&source.code),
+                    width = SOURCE_WIDTH
+                ),
+                None => line,
+            };
+            (source.line_number.to_string(), line)
+        },
+    );
+    format!("{:08x}  {}  {:>5}  {}", address, word, line_number, line)
+        .trim_end()
+        .to_string()
+}
+
+fn compose_text_rows(program: &Program) -> Vec<String> {
+    let mut rows: Vec<String> = vec![];
+    let mut current_address = TEXT_SECTION_MIN_ADDRESS;
+    program.texts.iter().for_each(|text| {
+        program
+            .labels
+            .iter()
+            .filter(|label| label.address == current_address)
+            .for_each(|label| rows.push(compose_row(label.address, None, &label.source)));
+        rows.push(compose_row(
+            current_address,
+            Some(text.to_word()),
+            &text.source,
+        ));
+        current_address += WORD;
+    });
+    program
+        .labels
+        .iter()
+        .filter(|label| label.address >= current_address)
+        .for_each(|label| rows.push(compose_row(label.address, None, &label.source)));
+    rows
+}
+
+fn compose_data_rows(program: &Program, endianness: Endianness) -> Vec<String> {
+    program
+        .data
+        .iter()
+        .map(|datum| {
+            compose_row(
+                datum.address,
+                Some(datum.to_word(endianness)),
+                &datum.source,
+            )
+        })
+        .collect()
+}
+
+fn compose_symbol_rows(program: &Program) -> Vec<String> {
+    collect_symbols(program)
+        .iter()
+        .filter(|symbol| symbol.section != Section::NONE)
+        .map(|symbol| {
+            format!(
+                "{:08x}  {:<7}  {}",
+                symbol.address,
+                symbol.section.to_name(),
+                symbol.name
+            )
+        })
+        .collect()
+}
+
+pub fn write_listing(program: &Program, endianness: Endianness, filepath: &str) {
+    let mut result = String::from("Address   Code       Line  Source\n");
+    result.push_str(".text\n");
+    compose_text_rows(program)
+        .iter()
+        .for_each(|row| result.push_str(&format!("{}\n", row)));
+    if !program.data.is_empty() {
+        result.push_str("\n.data\n");
+        compose_data_rows(program, endianness)
+            .iter()
+            .for_each(|row| result.push_str(&format!("{}\n", row)));
+    }
+    result.push_str("\nSymbols\nAddress   Section  Name\n");
+    compose_symbol_rows(program)
+        .iter()
+        .for_each(|row| result.push_str(&format!("{}\n", row)));
+
+    let mut file = File::create(filepath).expect("Failed to create listing file.");
+    write!(file, "{}", result).expect("Failed to write listing file.");
+}
@@ -1,93 +1,212 @@
 use std::env;
-use std::fs::File;
-use std::io::Write;
-
-mod constants;
-mod datum;
-mod instruction;
-mod label;
-mod line;
-mod pseudo_instruction;
-mod section;
-mod text;
-mod utils;
-
-use crate::constants::{TEXT_SECTION_MIN_ADDRESS, WORD};
-use crate::datum::{extract_data_from_lines, Datum};
-use crate::label::{get_addressed_labels, is_label, resolve_labels, Label};
-use crate::line::{compose_lines, Line};
-use crate::pseudo_instruction::disassemble_pseudo_instruction;
-use crate::section::{resolve_section, Section};
-use crate::text::{get_text_from_code, Text};
-use crate::utils::convert_int_to_binary;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process;
+
+use mipssembler::debugger::Debugger;
+use mipssembler::disassembler::disassemble;
+use mipssembler::gdb_server::GdbServer;
+use mipssembler::hazard::write_hazard_report;
+use mipssembler::input::read_image;
+use mipssembler::link_map::write_link_map;
+use mipssembler::listing::write_listing;
+use mipssembler::options::{
+    resolve_debug_options, resolve_disassembler_options, resolve_options, resolve_run_options,
+    RunOptions,
+};
+use mipssembler::output::resolve_output_format;
+use mipssembler::simulator::cache::{Cache, Caches};
+use mipssembler::simulator::device::console::Console;
+use mipssembler::simulator::device::display::Display;
+use mipssembler::simulator::device::timer::Timer;
+use mipssembler::simulator::pipeline::{Pipeline, PipelineOptions};
+use mipssembler::simulator::{Simulator, StopReason};
+use mipssembler::{assemble, assemble_with_nops, Program};
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let input_filepath = &args[1];
-    let output_filepath = &args[2];
-    let mut input_file = File::open(input_filepath).expect("Failed to read input file.");
+    match args.get(1).map(String::as_str) {
+        Some("disasm") => run_disassembler(&args),
+        Some("run") => run_simulator(&args),
+        Some("debug") => run_debugger(&args),
+        _ => run_assembler(&args),
+    }
+}
 
-    let lines = compose_lines(&mut input_file);
+fn run_disassembler(args: &[String]) {
+    let options = resolve_disassembler_options(args);
+    let bytes = fs::read(&options.input_filepath).expect("Failed to read input file.");
+    let image = read_image(&bytes, options.format.as_deref(), options.endianness);
+    let result = disassemble(&image, options.pseudo);
 
-    let data = extract_data_from_lines(&lines);
-    let codes = extract_codes(&lines, &data);
-    let labels = get_addressed_labels(&lines, &codes);
-    let texts = disassemble_instructions(&data, &labels, &codes);
+    match &options.output_filepath {
+        Some(output_filepath) => {
+            fs::write(output_filepath, result).expect("Failed to write output file.");
+            println!("Done!");
+        }
+        None => print!("{}", result),
+    }
+}
 
-    write_output(output_filepath, &data, &texts);
+fn compose_caches(options: &RunOptions) -> Option<Caches> {
+    if options.cache.is_none()
+        && options.instruction_cache.is_none()
+        && options.data_cache.is_none()
+    {
+        return None;
+    }
 
-    println!("Done!");
+    let trace: Option<Box<dyn Write>> = match (options.cache_trace, &options.cache_trace_filepath) {
+        (false, _) => None,
+        (true, Some(filepath)) => Some(Box::new(BufWriter::new(
+            File::create(filepath).expect("Failed to create cache trace file."),
+        ))),
+        (true, None) => Some(Box::new(io::stderr())),
+    };
+    Some(Caches {
+        instruction: options
+            .instruction_cache
+            .map(|config| Cache::new("L1I", config)),
+        data: options.data_cache.map(|config| Cache::new("L1D", config)),
+        unified: options.cache.map(|config| Cache::new("L1", config)),
+        trace,
+    })
 }
 
-fn extract_codes(lines: &[Line], data: &[Datum]) -> Vec<String> {
-    lines
-        .iter()
-        .filter(|line| {
-            line.section == Section::TEXT && resolve_section(line.text.as_ref().unwrap()).is_none()
-        })
-        .flat_map(|line| {
-            if !is_label(&line.text.as_ref().unwrap()) {
-                if let Some(pseudo_instruction_codes) =
-                    disassemble_pseudo_instruction(&line.text.as_ref().unwrap(), &data)
-                {
-                    pseudo_instruction_codes
-                } else {
-                    vec![line.text.clone().unwrap().trim_start().to_string()]
-                }
-            } else {
-                vec![line.text.clone().unwrap()]
-            }
-        })
-        .collect()
+fn attach_devices(simulator: &mut Simulator, options: &RunOptions) {
+    if options.mmio {
+        simulator.attach_device(Box::new(Console::default()));
+        simulator.attach_device(Box::new(Timer::default()));
+    }
+    if let Some(filepath) = &options.display_filepath {
+        simulator.attach_device(Box::new(Display::new(
+            options.display_width,
+            options.display_height,
+            options.display_address,
+            PathBuf::from(filepath),
+        )));
+    }
 }
 
-fn disassemble_instructions(data: &[Datum], labels: &[Label], codes: &[String]) -> Vec<Text> {
-    let mut current_address = TEXT_SECTION_MIN_ADDRESS;
-    codes
+fn print_warnings(program: &Program, input_filepath: &str) {
+    program
+        .warnings
         .iter()
-        .filter_map(|code| {
-            if resolve_labels(&code).is_none() {
-                let text = get_text_from_code(&code, current_address, &data, &labels);
-                current_address += WORD;
-                Some(text)
-            } else {
-                None
-            }
-        })
-        .collect()
+        .for_each(|warning| match warning.line_number {
+            Some(line_number) => eprintln!(
+                "Warning: {}:{}: {}",
+                input_filepath, line_number, warning.message
+            ),
+            None => eprintln!("Warning: {}", warning.message),
+        });
+}
+
+fn run_simulator(args: &[String]) {
+    let options = resolve_run_options(args);
+    let mut input_file = File::open(&options.input_filepath).expect("Failed to read input file.");
+    let program = assemble(&mut input_file, false, options.delay_slots);
+    print_warnings(&program, &options.input_filepath);
+    let entry = program.get_entry_address(options.entry.as_deref());
+
+    let mut simulator = Simulator::new(&program, entry, options.endianness);
+    simulator.sandbox = options.sandbox.clone().map(PathBuf::from);
+    simulator.caches = compose_caches(&options);
+    attach_devices(&mut simulator, &options);
+
+    if let Some(port) = options.gdb_port {
+        let listener =
+            TcpListener::bind(("127.0.0.1", port)).expect("Failed to listen for GDB connection.");
+        let address = listener
+            .local_addr()
+            .expect("Failed to listen for GDB connection.");
+        eprintln!("Waiting for GDB connection on {}.", address);
+
+        let mut server = GdbServer::new(simulator, options.max_steps);
+        server.serve(listener);
+        server.simulator.finish_devices();
+        eprintln!("GDB connection closed.");
+        return;
+    }
+
+    let stop_reason = if options.pipeline {
+        let mut pipeline = Pipeline::new(
+            &program,
+            PipelineOptions {
+                forwarding: options.forwarding,
+                branch_strategy: options.branch_strategy,
+            },
+        );
+        let stop_reason = pipeline.run(&mut simulator, options.max_steps);
+        simulator.output.flush().expect("Failed to write output.");
+        eprint!("{}", pipeline.format_summary());
+        stop_reason
+    } else {
+        simulator.run(options.max_steps)
+    };
+
+    simulator.finish_devices();
+    if let Some(caches) = simulator.caches.as_mut() {
+        caches.flush_trace();
+        eprint!("{}", caches.format_summary());
+    }
+    eprintln!("{}", simulator.describe_stop_reason(stop_reason));
+    eprint!("{}", simulator.format_registers());
+
+    match stop_reason {
+        StopReason::END => {}
+        StopReason::EXIT(exit_code) => process::exit(exit_code),
+        _ => process::exit(1),
+    }
 }
 
-fn write_output(filepath: &str, data: &[Datum], texts: &[Text]) {
-    let data_section_size = data.len() as i32 * WORD;
-    let text_section_size = texts.len() as i32 * WORD;
+fn run_debugger(args: &[String]) {
+    let options = resolve_debug_options(args);
+    let mut input_file = File::open(&options.input_filepath).expect("Failed to read input file.");
+    let program = assemble(&mut input_file, false, options.delay_slots);
+    print_warnings(&program, &options.input_filepath);
+    let entry = program.get_entry_address(options.entry.as_deref());
 
-    let data_section_size_binary = convert_int_to_binary(data_section_size, 32);
-    let text_section_size_binary = convert_int_to_binary(text_section_size, 32);
+    let mut simulator = Simulator::new(&program, entry, options.endianness);
+    simulator.sandbox = options.sandbox.clone().map(PathBuf::from);
+    attach_devices(&mut simulator, &options);
 
-    let mut result = vec![text_section_size_binary, data_section_size_binary];
-    result.extend(texts.iter().map(|text| text.to_binary()));
-    result.extend(data.iter().map(|datum| datum.to_binary()));
+    let mut debugger = Debugger::new(simulator, &program, options.max_steps);
+    debugger.run();
+    debugger.simulator.finish_devices();
+}
 
-    let mut file = File::create(filepath).expect("Failed to crate output file.");
-    write!(file, "{}", result.join("")).expect("Failed to write output file.");
+fn run_assembler(args: &[String]) {
+    let options = resolve_options(args);
+    let output = resolve_output_format(&options.format).expect("Unknown output format.");
+    let mut input_file = File::open(&options.input_filepath).expect("Failed to read input file.");
+    let delay_slots = options.delay_slots || output.has_delay_slots();
+
+    let program = if options.insert_nops {
+        assemble_with_nops(&mut input_file, output.is_relocatable(), delay_slots)
+    } else {
+        assemble(&mut input_file, output.is_relocatable(), delay_slots)
+    };
+    if program.has_kernel_sections() && !output.has_kernel_sections() {
+        panic!("Kernel sections can only be used with run, debug and the elf formats.");
+    }
+    print_warnings(&program, &options.input_filepath);
+
+    output.write(&program, &options);
+    if let Some(listing_filepath) = &options.listing_filepath {
+        write_listing(&program, options.endianness, listing_filepath);
+    }
+    if let Some(map_filepath) = &options.map_filepath {
+        write_link_map(&program, &options.input_filepath, map_filepath);
+    }
+    if options.hazards {
+        write_hazard_report(
+            &program,
+            options.hazard_window,
+            options.hazards_filepath.as_deref(),
+        );
+    }
+
+    println!("Done!");
 }
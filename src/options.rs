@@ -0,0 +1,345 @@
+use crate::endianness::Endianness;
+use crate::hazard::DEFAULT_HAZARD_WINDOW;
+use crate::simulator::cache::CacheConfig;
+use crate::simulator::device::display::{DISPLAY_ADDRESS, DISPLAY_HEIGHT, DISPLAY_WIDTH};
+use crate::simulator::pipeline::BranchStrategy;
+
+const USAGE: &str = "Usage: mipssembler [--format <format>] [-EB | -EL] [--entry <symbol>] \
+[--memory-depth <words>] [--word-width <bits>] [--fill <zero | nop>] [--address-markers] \
+[--address-width <bits>] [--base-offset <words>] [-l <listing>] [-Map <map>] \
+[--hazards[=<report>]] [--hazard-window <instructions>] [--insert-nops] [--delay-slots] \
+<input> <output>";
+
+const DISASSEMBLER_USAGE: &str =
+    "Usage: mipssembler disasm [--format <format>] [-EB | -EL] [--pseudo] <input> [<output>]";
+
+const RUN_USAGE: &str = "Usage: mipssembler run [-EB | -EL] [--entry <symbol>] [--delay-slots] \
+[--max-steps <steps>] [--sandbox <directory>] [--gdb <port>] [--pipeline] \
+[--forwarding <on | off>] [--branch-strategy <stall | predict-not-taken | delayed>] \
+[--cache <config> | --icache <config> --dcache <config>] [--cache-trace[=<trace>]] [--mmio] \
+[--display <image>] [--display-size <width>x<height>] [--display-base <address>] <input>";
+const DEBUG_USAGE: &str =
+    "Usage: mipssembler debug [-EB | -EL] [--entry <symbol>] [--delay-slots] \
+[--max-steps <steps>] [--sandbox <directory>] [--mmio] [--display <image>] \
+[--display-size <width>x<height>] [--display-base <address>] <input>";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Fill {
+    ZERO,
+    NOP,
+}
+
+pub struct Options {
+    pub input_filepath: String,
+    pub output_filepath: String,
+    pub format: String,
+    pub endianness: Endianness,
+    pub entry: Option<String>,
+    pub memory_depth: Option<usize>,
+    pub word_width: usize,
+    pub fill: Fill,
+    pub address_markers: bool,
+    pub address_width: Option<usize>,
+    pub base_offset: usize,
+    pub listing_filepath: Option<String>,
+    pub map_filepath: Option<String>,
+    pub hazards: bool,
+    pub hazards_filepath: Option<String>,
+    pub hazard_window: usize,
+    pub insert_nops: bool,
+    pub delay_slots: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            input_filepath: String::new(),
+            output_filepath: String::new(),
+            format: String::from("bits"),
+            endianness: Endianness::BIG,
+            entry: None,
+            memory_depth: None,
+            word_width: 32,
+            fill: Fill::ZERO,
+            address_markers: false,
+            address_width: None,
+            base_offset: 0,
+            listing_filepath: None,
+            map_filepath: None,
+            hazards: false,
+            hazards_filepath: None,
+            hazard_window: DEFAULT_HAZARD_WINDOW,
+            insert_nops: false,
+            delay_slots: false,
+        }
+    }
+}
+
+pub fn resolve_options(args: &[String]) -> Options {
+    let mut options = Options::default();
+    let mut filepaths: Vec<String> = vec![];
+
+    let mut args = args.iter().skip(1);
+    while let Some(arg) = args.next() {
+        let mut next_value = || args.next().expect(USAGE).clone();
+        match arg.as_str() {
+            "--format" | "-O" => options.format = next_value(),
+            "--entry" | "-e" => options.entry = Some(next_value()),
+            "--memory-depth" => {
+                options.memory_depth = Some(next_value().parse().expect("Invalid memory depth."))
+            }
+            "--word-width" => {
+                options.word_width = match next_value().as_str() {
+                    "8" => 8,
+                    "16" => 16,
+                    "32" => 32,
+                    _ => panic!("Word width must be 8, 16 or 32."),
+                }
+            }
+            "--fill" => {
+                options.fill = match next_value().as_str() {
+                    "zero" => Fill::ZERO,
+                    "nop" => Fill::NOP,
+                    _ => panic!("Fill must be zero or nop."),
+                }
+            }
+            "--address-markers" => options.address_markers = true,
+            "--address-width" => {
+                options.address_width = Some(next_value().parse().expect("Invalid address width."))
+            }
+            "--base-offset" => {
+                options.base_offset = next_value().parse().expect("Invalid base offset.")
+            }
+            "-l" => options.listing_filepath = Some(next_value()),
+            "-Map" => options.map_filepath = Some(next_value()),
+            "--hazards" => options.hazards = true,
+            "--hazard-window" => {
+                options.hazard_window = next_value().parse().expect("Invalid hazard window.")
+            }
+            "--insert-nops" => options.insert_nops = true,
+            "--delay-slots" => options.delay_slots = true,
+            "-EB" => options.endianness = Endianness::BIG,
+            "-EL" => options.endianness = Endianness::LITTLE,
+            _ => {
+                if let Some(value) = arg.strip_prefix("--format=") {
+                    options.format = value.to_string();
+                } else if let Some(value) = arg.strip_prefix("-Map=") {
+                    options.map_filepath = Some(value.to_string());
+                } else if let Some(value) = arg.strip_prefix("--hazards=") {
+                    options.hazards = true;
+                    options.hazards_filepath = Some(value.to_string());
+                } else {
+                    filepaths.push(arg.clone());
+                }
+            }
+        }
+    }
+
+    if let [input_filepath, output_filepath] = &filepaths[..] {
+        options.input_filepath = input_filepath.clone();
+        options.output_filepath = output_filepath.clone();
+        options
+    } else {
+        panic!("{}", USAGE);
+    }
+}
+
+pub struct DisassemblerOptions {
+    pub input_filepath: String,
+    pub output_filepath: Option<String>,
+    pub format: Option<String>,
+    pub endianness: Endianness,
+    pub pseudo: bool,
+}
+
+pub fn resolve_disassembler_options(args: &[String]) -> DisassemblerOptions {
+    let mut format = None;
+    let mut endianness = Endianness::BIG;
+    let mut pseudo = false;
+    let mut filepaths: Vec<String> = vec![];
+
+    let mut args = args.iter().skip(2);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-I" => format = Some(args.next().expect(DISASSEMBLER_USAGE).clone()),
+            "-EB" => endianness = Endianness::BIG,
+            "-EL" => endianness = Endianness::LITTLE,
+            "--pseudo" | "-p" => pseudo = true,
+            _ => {
+                if let Some(value) = arg.strip_prefix("--format=") {
+                    format = Some(value.to_string());
+                } else {
+                    filepaths.push(arg.clone());
+                }
+            }
+        }
+    }
+
+    match &filepaths[..] {
+        [input_filepath] => DisassemblerOptions {
+            input_filepath: input_filepath.clone(),
+            output_filepath: None,
+            format,
+            endianness,
+            pseudo,
+        },
+        [input_filepath, output_filepath] => DisassemblerOptions {
+            input_filepath: input_filepath.clone(),
+            output_filepath: Some(output_filepath.clone()),
+            format,
+            endianness,
+            pseudo,
+        },
+        _ => panic!("{}", DISASSEMBLER_USAGE),
+    }
+}
+
+pub struct RunOptions {
+    pub input_filepath: String,
+    pub endianness: Endianness,
+    pub entry: Option<String>,
+    pub delay_slots: bool,
+    pub max_steps: Option<u64>,
+    pub sandbox: Option<String>,
+    pub gdb_port: Option<u16>,
+    pub pipeline: bool,
+    pub forwarding: bool,
+    pub branch_strategy: BranchStrategy,
+    pub cache: Option<CacheConfig>,
+    pub instruction_cache: Option<CacheConfig>,
+    pub data_cache: Option<CacheConfig>,
+    pub cache_trace: bool,
+    pub cache_trace_filepath: Option<String>,
+    pub mmio: bool,
+    pub display_filepath: Option<String>,
+    pub display_width: u32,
+    pub display_height: u32,
+    pub display_address: u32,
+}
+
+fn resolve_simulator_options(args: &[String], usage: &str) -> RunOptions {
+    let mut options = RunOptions {
+        input_filepath: String::new(),
+        endianness: Endianness::BIG,
+        entry: None,
+        delay_slots: false,
+        max_steps: None,
+        sandbox: None,
+        gdb_port: None,
+        pipeline: false,
+        forwarding: true,
+        branch_strategy: BranchStrategy::PREDICTNOTTAKEN,
+        cache: None,
+        instruction_cache: None,
+        data_cache: None,
+        cache_trace: false,
+        cache_trace_filepath: None,
+        mmio: false,
+        display_filepath: None,
+        display_width: DISPLAY_WIDTH,
+        display_height: DISPLAY_HEIGHT,
+        display_address: DISPLAY_ADDRESS,
+    };
+    let mut branch_strategy = None;
+    let mut filepaths: Vec<String> = vec![];
+
+    let mut args = args.iter().skip(2);
+    while let Some(arg) = args.next() {
+        let mut next_value = || args.next().expect(usage).clone();
+        match arg.as_str() {
+            "--entry" | "-e" => options.entry = Some(next_value()),
+            "--delay-slots" => options.delay_slots = true,
+            "--max-steps" => {
+                options.max_steps = Some(next_value().parse().expect("Invalid step limit."))
+            }
+            "--sandbox" => options.sandbox = Some(next_value()),
+            "--gdb" => options.gdb_port = Some(next_value().parse().expect("Invalid port.")),
+            "--pipeline" => options.pipeline = true,
+            "--forwarding" => {
+                options.forwarding = match next_value().as_str() {
+                    "on" => true,
+                    "off" => false,
+                    _ => panic!("Unknown forwarding setting."),
+                }
+            }
+            "--branch-strategy" => {
+                branch_strategy = Some(
+                    BranchStrategy::from_name(&next_value()).expect("Unknown branch strategy."),
+                )
+            }
+            "--cache" => options.cache = Some(CacheConfig::parse(&next_value())),
+            "--icache" => options.instruction_cache = Some(CacheConfig::parse(&next_value())),
+            "--dcache" => options.data_cache = Some(CacheConfig::parse(&next_value())),
+            "--cache-trace" => options.cache_trace = true,
+            "--mmio" => options.mmio = true,
+            "--display" => options.display_filepath = Some(next_value()),
+            "--display-size" => {
+                let size = next_value();
+                let (width, height) = size
+                    .split_once('x')
+                    .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)))
+                    .filter(|&(width, height)| width > 0 && height > 0)
+                    .expect("Invalid display size.");
+                options.display_width = width;
+                options.display_height = height;
+            }
+            "--display-base" => {
+                let address = next_value();
+                options.display_address = match address.strip_prefix("0x") {
+                    Some(address) => u32::from_str_radix(address, 16),
+                    None => address.parse(),
+                }
+                .expect("Invalid display base address.")
+            }
+            "-EB" => options.endianness = Endianness::BIG,
+            "-EL" => options.endianness = Endianness::LITTLE,
+            _ => {
+                if let Some(value) = arg.strip_prefix("--cache-trace=") {
+                    options.cache_trace = true;
+                    options.cache_trace_filepath = Some(value.to_string());
+                } else {
+                    filepaths.push(arg.clone());
+                }
+            }
+        }
+    }
+
+    options.branch_strategy = match branch_strategy {
+        Some(strategy) if options.delay_slots && strategy != BranchStrategy::DELAYED => {
+            panic!("--delay-slots requires the delayed branch strategy.")
+        }
+        Some(BranchStrategy::DELAYED) if !options.delay_slots => {
+            panic!("The delayed branch strategy requires --delay-slots.")
+        }
+        Some(strategy) => strategy,
+        None if options.delay_slots => BranchStrategy::DELAYED,
+        None => BranchStrategy::PREDICTNOTTAKEN,
+    };
+    if options.cache.is_some()
+        && (options.instruction_cache.is_some() || options.data_cache.is_some())
+    {
+        panic!("--cache cannot be combined with --icache or --dcache.");
+    }
+    if options.cache_trace
+        && options.cache.is_none()
+        && options.instruction_cache.is_none()
+        && options.data_cache.is_none()
+    {
+        panic!("--cache-trace requires --cache, --icache or --dcache.");
+    }
+
+    if let [input_filepath] = &filepaths[..] {
+        options.input_filepath = input_filepath.clone();
+        options
+    } else {
+        panic!("{}", usage);
+    }
+}
+
+pub fn resolve_run_options(args: &[String]) -> RunOptions {
+    resolve_simulator_options(args, RUN_USAGE)
+}
+
+pub fn resolve_debug_options(args: &[String]) -> RunOptions {
+    resolve_simulator_options(args, DEBUG_USAGE)
+}
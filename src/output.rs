@@ -0,0 +1,112 @@
+use std::path::Path;
+
+use crate::constants::{DATA_SECTION_MIN_ADDRESS, TEXT_SECTION_MIN_ADDRESS};
+use crate::endianness::Endianness;
+use crate::options::Options;
+use crate::Program;
+
+mod array;
+mod bin;
+mod bits;
+mod elf;
+mod ihex;
+mod json;
+mod logisim;
+mod memory;
+mod srec;
+
+use crate::output::array::{Array, ArrayLanguage};
+use crate::output::bin::Bin;
+use crate::output::bits::Bits;
+use crate::output::elf::{Elf, ElfExec};
+use crate::output::ihex::Ihex;
+use crate::output::json::Json;
+use crate::output::logisim::Logisim;
+use crate::output::memory::{Memory, MemoryFormat};
+use crate::output::srec::Srec;
+
+pub struct Segment {
+    pub name: &'static str,
+    pub address: u32,
+    pub bytes: Vec<u8>,
+}
+
+pub trait Output {
+    fn write(&self, program: &Program, options: &Options);
+
+    fn is_relocatable(&self) -> bool {
+        false
+    }
+
+    fn has_kernel_sections(&self) -> bool {
+        false
+    }
+
+    fn has_delay_slots(&self) -> bool {
+        false
+    }
+}
+
+pub fn resolve_output_format(name: &str) -> Option<Box<dyn Output>> {
+    match name {
+        "bits" => Some(Box::new(Bits)),
+        "bin" | "binary" => Some(Box::new(Bin)),
+        "elf" => Some(Box::new(Elf)),
+        "elf-exec" => Some(Box::new(ElfExec)),
+        "ihex" => Some(Box::new(Ihex)),
+        "srec" => Some(Box::new(Srec)),
+        "json" => Some(Box::new(Json)),
+        "readmemh" => Some(Box::new(Memory::new(MemoryFormat::READMEMH))),
+        "readmemb" => Some(Box::new(Memory::new(MemoryFormat::READMEMB))),
+        "coe" => Some(Box::new(Memory::new(MemoryFormat::COE))),
+        "mif" => Some(Box::new(Memory::new(MemoryFormat::MIF))),
+        "logisim" => Some(Box::new(Logisim)),
+        "c-array" => Some(Box::new(Array::new(ArrayLanguage::C))),
+        "rust-array" => Some(Box::new(Array::new(ArrayLanguage::RUST))),
+        _ => None,
+    }
+}
+
+pub fn collect_segments(program: &Program, endianness: Endianness) -> Vec<Segment> {
+    let text = Segment {
+        name: "text",
+        address: TEXT_SECTION_MIN_ADDRESS as u32,
+        bytes: program
+            .texts
+            .iter()
+            .flat_map(|text| text.to_bytes(endianness))
+            .collect(),
+    };
+    let data = Segment {
+        name: "data",
+        address: DATA_SECTION_MIN_ADDRESS as u32,
+        bytes: program
+            .data
+            .iter()
+            .flat_map(|datum| datum.to_bytes(endianness))
+            .collect(),
+    };
+
+    vec![text, data]
+        .into_iter()
+        .filter(|segment| !segment.bytes.is_empty())
+        .collect()
+}
+
+pub fn compose_segment_filepath(filepath: &str, segment: &Segment) -> String {
+    let path = Path::new(filepath);
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("");
+    let filename = match path.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) => format!("{}.{}.{}", stem, segment.name, extension),
+        None => format!("{}.{}", stem, segment.name),
+    };
+
+    path.with_file_name(filename).to_string_lossy().into_owned()
+}
+
+pub fn resolve_entry_address(program: &Program, options: &Options) -> u32 {
+    program.get_entry_address(options.entry.as_deref())
+}
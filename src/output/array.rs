@@ -0,0 +1,126 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::constants::{DATA_SECTION_MIN_ADDRESS, TEXT_SECTION_MIN_ADDRESS};
+use crate::datum::find_datum;
+use crate::label::find_label;
+use crate::options::Options;
+use crate::output::{resolve_entry_address, Output};
+use crate::Program;
+
+const WORDS_PER_LINE: usize = 4;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ArrayLanguage {
+    C,
+    RUST,
+}
+
+pub struct Array {
+    language: ArrayLanguage,
+}
+
+impl Array {
+    pub fn new(language: ArrayLanguage) -> Self {
+        Self { language }
+    }
+
+    fn compose_constant(&self, name: &str, literal: &str) -> String {
+        match self.language {
+            ArrayLanguage::C => format!("#define {} {}u\n", name, literal),
+            ArrayLanguage::RUST => format!("pub const {}: u32 = {};\n", name, literal),
+        }
+    }
+
+    fn compose_address(&self, name: &str, address: u32) -> String {
+        self.compose_constant(name, &format!("0x{:08x}", address))
+    }
+
+    fn compose_array(&self, name: &str, words: &[u32]) -> String {
+        let body = words
+            .chunks(WORDS_PER_LINE)
+            .map(|chunk| {
+                let line = chunk
+                    .iter()
+                    .map(|word| format!("0x{:08x},", word))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                format!("    {}\n", line)
+            })
+            .collect::<String>();
+
+        match self.language {
+            ArrayLanguage::C if words.is_empty() => {
+                format!("const uint32_t {}[1] = {{ 0 }};\n", name.to_lowercase())
+            }
+            ArrayLanguage::C => format!(
+                "const uint32_t {}[] = {{\n{}}};\n",
+                name.to_lowercase(),
+                body
+            ),
+            ArrayLanguage::RUST => format!(
+                "pub const {}: [u32; {}] = [\n{}];\n",
+                name,
+                words.len(),
+                body
+            ),
+        }
+    }
+}
+
+impl Output for Array {
+    fn write(&self, program: &Program, options: &Options) {
+        let texts = program
+            .texts
+            .iter()
+            .map(|text| text.to_word())
+            .collect::<Vec<u32>>();
+        let data = program
+            .data
+            .iter()
+            .map(|datum| datum.to_word(options.endianness))
+            .collect::<Vec<u32>>();
+
+        let mut result = match self.language {
+            ArrayLanguage::C => String::from("#include <stdint.h>\n\n"),
+            ArrayLanguage::RUST => String::new(),
+        };
+
+        result
+            .push_str(&self.compose_address("TEXT_BASE_ADDRESS", TEXT_SECTION_MIN_ADDRESS as u32));
+        result
+            .push_str(&self.compose_address("DATA_BASE_ADDRESS", DATA_SECTION_MIN_ADDRESS as u32));
+        result.push_str(
+            &self.compose_address("ENTRY_ADDRESS", resolve_entry_address(program, options)),
+        );
+        result.push_str(&self.compose_constant("TEXT_WORD_COUNT", &texts.len().to_string()));
+        result.push_str(&self.compose_constant("DATA_WORD_COUNT", &data.len().to_string()));
+        result.push('\n');
+
+        let symbols = program
+            .globals
+            .iter()
+            .filter_map(|name| {
+                find_label(name, &program.labels)
+                    .map(|label| label.address)
+                    .or_else(|| find_datum(name, &program.data).map(|datum| datum.address))
+                    .map(|address| (name, address))
+            })
+            .collect::<Vec<(&String, i32)>>();
+        if !symbols.is_empty() {
+            symbols.iter().for_each(|(name, address)| {
+                let name = format!("SYMBOL_{}", name.to_uppercase());
+                result.push_str(&self.compose_address(&name, *address as u32));
+            });
+            result.push('\n');
+        }
+
+        result.push_str(&self.compose_array("TEXT", &texts));
+        result.push('\n');
+        result.push_str(&self.compose_array("DATA", &data));
+
+        let mut file =
+            File::create(&options.output_filepath).expect("Failed to crate output file.");
+        write!(file, "{}", result).expect("Failed to write output file.");
+    }
+}
@@ -0,0 +1,41 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::constants::WORD;
+use crate::options::Options;
+use crate::output::Output;
+use crate::Program;
+
+const MAGIC: &[u8; 4] = b"MIPS";
+
+pub struct Bin;
+
+impl Output for Bin {
+    fn write(&self, program: &Program, options: &Options) {
+        let endianness = options.endianness;
+        let data_section_size = program.data.len() as u32 * WORD as u32;
+        let text_section_size = program.texts.len() as u32 * WORD as u32;
+
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend(&[endianness.to_ident(), 0, 0, 0]);
+        bytes.extend(&endianness.word_to_bytes(text_section_size));
+        bytes.extend(&endianness.word_to_bytes(data_section_size));
+        bytes.extend(
+            program
+                .texts
+                .iter()
+                .flat_map(|text| text.to_bytes(endianness)),
+        );
+        bytes.extend(
+            program
+                .data
+                .iter()
+                .flat_map(|datum| datum.to_bytes(endianness)),
+        );
+
+        let mut file =
+            File::create(&options.output_filepath).expect("Failed to crate output file.");
+        file.write_all(&bytes)
+            .expect("Failed to write output file.");
+    }
+}
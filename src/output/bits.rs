@@ -0,0 +1,33 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::constants::WORD;
+use crate::options::Options;
+use crate::output::Output;
+use crate::utils::convert_int_to_binary;
+use crate::Program;
+
+pub struct Bits;
+
+impl Output for Bits {
+    fn write(&self, program: &Program, options: &Options) {
+        let data_section_size = program.data.len() as i32 * WORD;
+        let text_section_size = program.texts.len() as i32 * WORD;
+
+        let data_section_size_binary = convert_int_to_binary(data_section_size, 32);
+        let text_section_size_binary = convert_int_to_binary(text_section_size, 32);
+
+        let mut result = vec![text_section_size_binary, data_section_size_binary];
+        result.extend(program.texts.iter().map(|text| text.to_binary()));
+        result.extend(
+            program
+                .data
+                .iter()
+                .map(|datum| datum.to_binary(options.endianness)),
+        );
+
+        let mut file =
+            File::create(&options.output_filepath).expect("Failed to crate output file.");
+        write!(file, "{}", result.join("")).expect("Failed to write output file.");
+    }
+}
@@ -0,0 +1,803 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::constants::{
+    DATA_SECTION_MIN_ADDRESS, KERNEL_DATA_SECTION_MIN_ADDRESS, KERNEL_TEXT_SECTION_MIN_ADDRESS,
+    TEXT_SECTION_MIN_ADDRESS, WORD,
+};
+use crate::datum::Datum;
+use crate::endianness::Endianness;
+use crate::options::Options;
+use crate::output::{resolve_entry_address, Output};
+use crate::relocation::RelocationType;
+use crate::text::Text;
+use crate::Program;
+
+const ELF_HEADER_SIZE: u32 = 52;
+const PROGRAM_HEADER_SIZE: u32 = 32;
+const SECTION_HEADER_SIZE: u32 = 40;
+const SYMBOL_SIZE: u32 = 16;
+const RELOCATION_SIZE: u32 = 8;
+const PAGE_SIZE: usize = 0x1000;
+
+const ET_REL: u16 = 1;
+const ET_EXEC: u16 = 2;
+const EM_MIPS: u16 = 8;
+const EF_MIPS_NOREORDER: u32 = 0x1;
+const EF_MIPS_ABI_O32: u32 = 0x1000;
+const EF_MIPS_ARCH_32: u32 = 0x5000_0000;
+
+const PT_LOAD: u32 = 1;
+const PF_X: u32 = 0x1;
+const PF_W: u32 = 0x2;
+const PF_R: u32 = 0x4;
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_NOBITS: u32 = 8;
+const SHT_REL: u32 = 9;
+
+const SHF_WRITE: u32 = 0x1;
+const SHF_ALLOC: u32 = 0x2;
+const SHF_EXECINSTR: u32 = 0x4;
+
+const STB_LOCAL: u8 = 0;
+const STB_GLOBAL: u8 = 1;
+const STT_NOTYPE: u8 = 0;
+const STT_OBJECT: u8 = 1;
+const STT_SECTION: u8 = 3;
+
+const SHN_UNDEF: u16 = 0;
+const TEXT_SECTION_INDEX: u16 = 1;
+const DATA_SECTION_INDEX: u16 = 2;
+const BSS_SECTION_INDEX: u16 = 3;
+const KERNEL_TEXT_SECTION_INDEX: u16 = 4;
+const KERNEL_DATA_SECTION_INDEX: u16 = 5;
+
+pub struct Elf;
+
+pub struct ElfExec;
+
+struct Buffer {
+    bytes: Vec<u8>,
+    endianness: Endianness,
+}
+
+impl Buffer {
+    fn new(endianness: Endianness) -> Self {
+        Self {
+            bytes: vec![],
+            endianness,
+        }
+    }
+
+    fn push_byte(&mut self, byte: u8) {
+        self.bytes.push(byte);
+    }
+
+    fn push_half(&mut self, half: u16) {
+        self.bytes.extend(&self.endianness.half_to_bytes(half));
+    }
+
+    fn push_word(&mut self, word: u32) {
+        self.bytes.extend(&self.endianness.word_to_bytes(word));
+    }
+
+    fn align(&mut self, alignment: usize) {
+        while !self.bytes.len().is_multiple_of(alignment) {
+            self.bytes.push(0);
+        }
+    }
+
+    fn align_to_page(&mut self, address: u32) {
+        while self.bytes.len() % PAGE_SIZE != address as usize % PAGE_SIZE {
+            self.bytes.push(0);
+        }
+    }
+
+    fn len(&self) -> u32 {
+        self.bytes.len() as u32
+    }
+}
+
+struct StringTable {
+    bytes: Vec<u8>,
+}
+
+impl StringTable {
+    fn new() -> Self {
+        Self { bytes: vec![0] }
+    }
+
+    fn add(&mut self, name: &str) -> u32 {
+        let offset = self.bytes.len() as u32;
+        self.bytes.extend(name.as_bytes());
+        self.bytes.push(0);
+        offset
+    }
+}
+
+struct Symbol {
+    name: String,
+    value: u32,
+    size: u32,
+    binding: u8,
+    symbol_type: u8,
+    section_index: u16,
+}
+
+impl Symbol {
+    fn section(section_index: u16) -> Self {
+        Self {
+            name: String::new(),
+            value: 0,
+            size: 0,
+            binding: STB_LOCAL,
+            symbol_type: if section_index == SHN_UNDEF {
+                STT_NOTYPE
+            } else {
+                STT_SECTION
+            },
+            section_index,
+        }
+    }
+}
+
+struct SectionHeader {
+    name: u32,
+    section_type: u32,
+    flags: u32,
+    address: u32,
+    offset: u32,
+    size: u32,
+    link: u32,
+    info: u32,
+    alignment: u32,
+    entry_size: u32,
+}
+
+impl SectionHeader {
+    fn null() -> Self {
+        Self {
+            name: 0,
+            section_type: 0,
+            flags: 0,
+            address: 0,
+            offset: 0,
+            size: 0,
+            link: 0,
+            info: 0,
+            alignment: 0,
+            entry_size: 0,
+        }
+    }
+}
+
+struct ProgramHeader {
+    offset: u32,
+    address: u32,
+    size: u32,
+    flags: u32,
+}
+
+struct ElfBuilder {
+    buffer: Buffer,
+    shstrtab: StringTable,
+    section_headers: Vec<SectionHeader>,
+}
+
+impl ElfBuilder {
+    fn new(endianness: Endianness, program_header_count: u32) -> Self {
+        let mut buffer = Buffer::new(endianness);
+        let header_size = ELF_HEADER_SIZE + program_header_count * PROGRAM_HEADER_SIZE;
+        buffer.bytes.resize(header_size as usize, 0);
+
+        Self {
+            buffer,
+            shstrtab: StringTable::new(),
+            section_headers: vec![SectionHeader::null()],
+        }
+    }
+
+    fn add_section(&mut self, name: &str, section_header: SectionHeader, bytes: &[u8]) -> u32 {
+        self.buffer.align(section_header.alignment.max(1) as usize);
+        let offset = self.buffer.len();
+        let size = if section_header.section_type == SHT_NOBITS {
+            section_header.size
+        } else {
+            self.buffer.bytes.extend(bytes);
+            bytes.len() as u32
+        };
+
+        self.section_headers.push(SectionHeader {
+            name: self.shstrtab.add(name),
+            offset,
+            size,
+            ..section_header
+        });
+        self.section_headers.len() as u32 - 1
+    }
+
+    fn add_symbols(&mut self, symbols: &[Symbol]) -> u32 {
+        let mut strtab = StringTable::new();
+        let mut symtab = Buffer::new(self.buffer.endianness);
+        symbols.iter().for_each(|symbol| {
+            let name = if symbol.name.is_empty() {
+                0
+            } else {
+                strtab.add(&symbol.name)
+            };
+            symtab.push_word(name);
+            symtab.push_word(symbol.value);
+            symtab.push_word(symbol.size);
+            symtab.push_byte(symbol.binding << 4 | symbol.symbol_type);
+            symtab.push_byte(0);
+            symtab.push_half(symbol.section_index);
+        });
+
+        let first_global_index = symbols
+            .iter()
+            .position(|symbol| symbol.binding == STB_GLOBAL)
+            .unwrap_or(symbols.len()) as u32;
+        let strtab_index = self.section_headers.len() as u32 + 1;
+
+        let symtab_index = self.add_section(
+            ".symtab",
+            SectionHeader {
+                section_type: SHT_SYMTAB,
+                link: strtab_index,
+                info: first_global_index,
+                alignment: WORD as u32,
+                entry_size: SYMBOL_SIZE,
+                ..SectionHeader::null()
+            },
+            &symtab.bytes,
+        );
+        self.add_section(
+            ".strtab",
+            SectionHeader {
+                section_type: SHT_STRTAB,
+                alignment: 1,
+                ..SectionHeader::null()
+            },
+            &strtab.bytes,
+        );
+
+        symtab_index
+    }
+
+    fn add_relocations(&mut self, name: &str, symtab_index: u32, section_index: u16, bytes: &[u8]) {
+        self.add_section(
+            name,
+            SectionHeader {
+                section_type: SHT_REL,
+                link: symtab_index,
+                info: section_index as u32,
+                alignment: WORD as u32,
+                entry_size: RELOCATION_SIZE,
+                ..SectionHeader::null()
+            },
+            bytes,
+        );
+    }
+
+    fn get_offset(&self, section_index: u32) -> u32 {
+        self.section_headers[section_index as usize].offset
+    }
+
+    fn finish(
+        mut self,
+        elf_type: u16,
+        entry: u32,
+        program_headers: &[ProgramHeader],
+        delay_slots: bool,
+    ) -> Vec<u8> {
+        let shstrtab_name = self.shstrtab.add(".shstrtab");
+        let shstrtab_offset = self.buffer.len();
+        self.buffer.bytes.extend(&self.shstrtab.bytes);
+        self.section_headers.push(SectionHeader {
+            name: shstrtab_name,
+            section_type: SHT_STRTAB,
+            offset: shstrtab_offset,
+            size: self.shstrtab.bytes.len() as u32,
+            alignment: 1,
+            ..SectionHeader::null()
+        });
+
+        self.buffer.align(WORD as usize);
+        let section_headers_offset = self.buffer.len();
+        let buffer = &mut self.buffer;
+        self.section_headers.iter().for_each(|section_header| {
+            buffer.push_word(section_header.name);
+            buffer.push_word(section_header.section_type);
+            buffer.push_word(section_header.flags);
+            buffer.push_word(section_header.address);
+            buffer.push_word(section_header.offset);
+            buffer.push_word(section_header.size);
+            buffer.push_word(section_header.link);
+            buffer.push_word(section_header.info);
+            buffer.push_word(section_header.alignment);
+            buffer.push_word(section_header.entry_size);
+        });
+
+        let endianness = self.buffer.endianness;
+        let mut header = Buffer::new(endianness);
+        header
+            .bytes
+            .extend(&[0x7f, b'E', b'L', b'F', 1, endianness.to_ident(), 1, 0]);
+        header.bytes.resize(16, 0);
+        header.push_half(elf_type);
+        header.push_half(EM_MIPS);
+        header.push_word(1);
+        header.push_word(entry);
+        header.push_word(if program_headers.is_empty() {
+            0
+        } else {
+            ELF_HEADER_SIZE
+        });
+        header.push_word(section_headers_offset);
+        header.push_word(if delay_slots {
+            EF_MIPS_ARCH_32 | EF_MIPS_ABI_O32 | EF_MIPS_NOREORDER
+        } else {
+            EF_MIPS_ARCH_32 | EF_MIPS_ABI_O32
+        });
+        header.push_half(ELF_HEADER_SIZE as u16);
+        header.push_half(if program_headers.is_empty() {
+            0
+        } else {
+            PROGRAM_HEADER_SIZE as u16
+        });
+        header.push_half(program_headers.len() as u16);
+        header.push_half(SECTION_HEADER_SIZE as u16);
+        header.push_half(self.section_headers.len() as u16);
+        header.push_half(self.section_headers.len() as u16 - 1);
+
+        program_headers.iter().for_each(|program_header| {
+            header.push_word(PT_LOAD);
+            header.push_word(program_header.offset);
+            header.push_word(program_header.address);
+            header.push_word(program_header.address);
+            header.push_word(program_header.size);
+            header.push_word(program_header.size);
+            header.push_word(program_header.flags);
+            header.push_word(PAGE_SIZE as u32);
+        });
+
+        self.buffer.bytes[..header.bytes.len()].copy_from_slice(&header.bytes);
+        self.buffer.bytes
+    }
+}
+
+impl Output for Elf {
+    fn write(&self, program: &Program, options: &Options) {
+        let endianness = options.endianness;
+        let symbols = collect_symbols(program, true);
+        let find_symbol_index = |name: &str| {
+            symbols
+                .iter()
+                .position(|symbol| symbol.name == name)
+                .expect("Use of undeclared symbol.") as u32
+        };
+
+        let (kernel_data, data): (Vec<&Datum>, Vec<&Datum>) =
+            program.data.iter().partition(|datum| datum.is_kernel());
+        let (text, text_relocations) = encode_texts(&program.texts, endianness, &find_symbol_index);
+        let (data, data_relocations) = encode_data(
+            &data,
+            DATA_SECTION_MIN_ADDRESS,
+            endianness,
+            &find_symbol_index,
+        );
+        let (kernel_text, kernel_text_relocations) =
+            encode_texts(&program.kernel_texts, endianness, &find_symbol_index);
+        let (kernel_data, kernel_data_relocations) = encode_data(
+            &kernel_data,
+            KERNEL_DATA_SECTION_MIN_ADDRESS,
+            endianness,
+            &find_symbol_index,
+        );
+
+        let mut builder = ElfBuilder::new(endianness, 0);
+        add_program_sections(&mut builder, &text.bytes, &data.bytes, false);
+        if program.has_kernel_sections() {
+            add_kernel_sections(&mut builder, &kernel_text.bytes, &kernel_data.bytes, false);
+        }
+        let symtab_index = builder.add_symbols(&symbols);
+        builder.add_relocations(
+            ".rel.text",
+            symtab_index,
+            TEXT_SECTION_INDEX,
+            &text_relocations.bytes,
+        );
+        builder.add_relocations(
+            ".rel.data",
+            symtab_index,
+            DATA_SECTION_INDEX,
+            &data_relocations.bytes,
+        );
+        if program.has_kernel_sections() {
+            builder.add_relocations(
+                ".rel.ktext",
+                symtab_index,
+                KERNEL_TEXT_SECTION_INDEX,
+                &kernel_text_relocations.bytes,
+            );
+            builder.add_relocations(
+                ".rel.kdata",
+                symtab_index,
+                KERNEL_DATA_SECTION_INDEX,
+                &kernel_data_relocations.bytes,
+            );
+        }
+
+        write_file(
+            &options.output_filepath,
+            &builder.finish(ET_REL, 0, &[], program.delay_slots),
+        );
+    }
+
+    fn is_relocatable(&self) -> bool {
+        true
+    }
+
+    fn has_kernel_sections(&self) -> bool {
+        true
+    }
+}
+
+impl Output for ElfExec {
+    fn write(&self, program: &Program, options: &Options) {
+        let endianness = options.endianness;
+        if let Some(name) = program.get_externs().first() {
+            panic!("Undefined symbol in executable: {}", name);
+        }
+
+        let collect_bytes = |kernel: bool| {
+            program
+                .data
+                .iter()
+                .filter(|datum| datum.is_kernel() == kernel)
+                .flat_map(|datum| datum.to_bytes(endianness))
+                .collect::<Vec<u8>>()
+        };
+        let text = program
+            .texts
+            .iter()
+            .flat_map(|text| text.to_bytes(endianness))
+            .collect::<Vec<u8>>();
+        let data = collect_bytes(false);
+        let kernel_text = program
+            .kernel_texts
+            .iter()
+            .flat_map(|text| text.to_bytes(endianness))
+            .collect::<Vec<u8>>();
+        let kernel_data = collect_bytes(true);
+
+        let mut segments = vec![(
+            TEXT_SECTION_INDEX,
+            TEXT_SECTION_MIN_ADDRESS,
+            text.len(),
+            PF_R | PF_X,
+        )];
+        segments.extend(
+            [
+                (
+                    DATA_SECTION_INDEX,
+                    DATA_SECTION_MIN_ADDRESS,
+                    data.len(),
+                    PF_R | PF_W,
+                ),
+                (
+                    KERNEL_TEXT_SECTION_INDEX,
+                    KERNEL_TEXT_SECTION_MIN_ADDRESS,
+                    kernel_text.len(),
+                    PF_R | PF_X,
+                ),
+                (
+                    KERNEL_DATA_SECTION_INDEX,
+                    KERNEL_DATA_SECTION_MIN_ADDRESS,
+                    kernel_data.len(),
+                    PF_R | PF_W,
+                ),
+            ]
+            .iter()
+            .filter(|(_, _, size, _)| *size > 0)
+            .copied(),
+        );
+
+        let mut builder = ElfBuilder::new(endianness, segments.len() as u32);
+        add_program_sections(&mut builder, &text, &data, true);
+        if program.has_kernel_sections() {
+            add_kernel_sections(&mut builder, &kernel_text, &kernel_data, true);
+        }
+        builder.add_symbols(&collect_symbols(program, false));
+
+        let program_headers = segments
+            .iter()
+            .map(|(section_index, address, size, flags)| ProgramHeader {
+                offset: builder.get_offset(*section_index as u32),
+                address: *address as u32,
+                size: *size as u32,
+                flags: *flags,
+            })
+            .collect::<Vec<ProgramHeader>>();
+
+        let bytes = builder.finish(
+            ET_EXEC,
+            resolve_entry_address(program, options),
+            &program_headers,
+            program.delay_slots,
+        );
+        write_file(&options.output_filepath, &bytes);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&options.output_filepath, PermissionsExt::from_mode(0o755))
+                .expect("Failed to set output file permissions.");
+        }
+    }
+
+    fn has_kernel_sections(&self) -> bool {
+        true
+    }
+
+    fn has_delay_slots(&self) -> bool {
+        true
+    }
+}
+
+fn encode_texts(
+    texts: &[Text],
+    endianness: Endianness,
+    find_symbol_index: &dyn Fn(&str) -> u32,
+) -> (Buffer, Buffer) {
+    let mut bytes = Buffer::new(endianness);
+    let mut relocations = Buffer::new(endianness);
+    texts.iter().enumerate().for_each(|(index, code)| {
+        if let Some(relocation) = &code.relocation {
+            let relocation_type = relocation.relocation_type;
+            bytes.push_word(relocation_type.to_addend_word(code.to_word()));
+            relocations.push_word(index as u32 * WORD as u32);
+            relocations.push_word(
+                find_symbol_index(&relocation.symbol) << 8 | relocation_type.to_code() as u32,
+            );
+        } else {
+            bytes.push_word(code.to_word());
+        }
+    });
+    (bytes, relocations)
+}
+
+fn encode_data(
+    data: &[&Datum],
+    base_address: i32,
+    endianness: Endianness,
+    find_symbol_index: &dyn Fn(&str) -> u32,
+) -> (Buffer, Buffer) {
+    let mut bytes = Buffer::new(endianness);
+    let mut relocations = Buffer::new(endianness);
+    data.iter().for_each(|datum| {
+        if let Some(symbol) = &datum.symbol {
+            let relocation_type = RelocationType::WORD32;
+            bytes.push_word(relocation_type.to_addend_word(datum.to_word(endianness)));
+            relocations.push_word(datum.address.wrapping_sub(base_address) as u32);
+            relocations
+                .push_word(find_symbol_index(symbol) << 8 | relocation_type.to_code() as u32);
+        } else {
+            bytes.push_word(datum.to_word(endianness));
+        }
+    });
+    (bytes, relocations)
+}
+
+fn add_program_sections(builder: &mut ElfBuilder, text: &[u8], data: &[u8], is_executable: bool) {
+    let (text_address, data_address) = if is_executable {
+        builder.buffer.align(PAGE_SIZE);
+        (
+            TEXT_SECTION_MIN_ADDRESS as u32,
+            DATA_SECTION_MIN_ADDRESS as u32,
+        )
+    } else {
+        (0, 0)
+    };
+
+    builder.add_section(
+        ".text",
+        SectionHeader {
+            section_type: SHT_PROGBITS,
+            flags: SHF_ALLOC | SHF_EXECINSTR,
+            address: text_address,
+            alignment: WORD as u32,
+            ..SectionHeader::null()
+        },
+        text,
+    );
+
+    if is_executable {
+        builder.buffer.align(PAGE_SIZE);
+    }
+    builder.add_section(
+        ".data",
+        SectionHeader {
+            section_type: SHT_PROGBITS,
+            flags: SHF_WRITE | SHF_ALLOC,
+            address: data_address,
+            alignment: WORD as u32,
+            ..SectionHeader::null()
+        },
+        data,
+    );
+
+    builder.add_section(
+        ".bss",
+        SectionHeader {
+            section_type: SHT_NOBITS,
+            flags: SHF_WRITE | SHF_ALLOC,
+            address: if is_executable {
+                data_address + data.len() as u32
+            } else {
+                0
+            },
+            alignment: WORD as u32,
+            ..SectionHeader::null()
+        },
+        &[],
+    );
+}
+
+fn add_kernel_sections(
+    builder: &mut ElfBuilder,
+    kernel_text: &[u8],
+    kernel_data: &[u8],
+    is_executable: bool,
+) {
+    let (text_address, data_address) = if is_executable {
+        (
+            KERNEL_TEXT_SECTION_MIN_ADDRESS as u32,
+            KERNEL_DATA_SECTION_MIN_ADDRESS as u32,
+        )
+    } else {
+        (0, 0)
+    };
+
+    if is_executable {
+        builder.buffer.align_to_page(text_address);
+    }
+    builder.add_section(
+        ".ktext",
+        SectionHeader {
+            section_type: SHT_PROGBITS,
+            flags: SHF_ALLOC | SHF_EXECINSTR,
+            address: text_address,
+            alignment: WORD as u32,
+            ..SectionHeader::null()
+        },
+        kernel_text,
+    );
+
+    if is_executable {
+        builder.buffer.align_to_page(data_address);
+    }
+    builder.add_section(
+        ".kdata",
+        SectionHeader {
+            section_type: SHT_PROGBITS,
+            flags: SHF_WRITE | SHF_ALLOC,
+            address: data_address,
+            alignment: WORD as u32,
+            ..SectionHeader::null()
+        },
+        kernel_data,
+    );
+}
+
+fn get_datum_size(data: &[Datum], datum: &Datum) -> u32 {
+    let mut region = data
+        .iter()
+        .filter(|next| next.is_kernel() == datum.is_kernel() && next.address > datum.address);
+    let end_address = region
+        .clone()
+        .find(|next| next.is_named)
+        .map(|next| next.address)
+        .unwrap_or_else(|| {
+            region
+                .next_back()
+                .map_or(datum.address, |last| last.address)
+                + WORD
+        });
+    end_address.wrapping_sub(datum.address) as u32
+}
+
+fn collect_symbols(program: &Program, is_relocatable: bool) -> Vec<Symbol> {
+    let relative = |address: i32, base_address: i32| {
+        if is_relocatable {
+            address.wrapping_sub(base_address) as u32
+        } else {
+            address as u32
+        }
+    };
+
+    let mut section_indexes = vec![
+        SHN_UNDEF,
+        TEXT_SECTION_INDEX,
+        DATA_SECTION_INDEX,
+        BSS_SECTION_INDEX,
+    ];
+    if program.has_kernel_sections() {
+        section_indexes.extend([KERNEL_TEXT_SECTION_INDEX, KERNEL_DATA_SECTION_INDEX]);
+    }
+    let mut symbols = section_indexes
+        .iter()
+        .map(|section_index| Symbol::section(*section_index))
+        .collect::<Vec<Symbol>>();
+
+    let mut defined_symbols = program
+        .labels
+        .iter()
+        .map(|label| {
+            let (base_address, section_index) =
+                if label.address as u32 >= KERNEL_TEXT_SECTION_MIN_ADDRESS as u32 {
+                    (KERNEL_TEXT_SECTION_MIN_ADDRESS, KERNEL_TEXT_SECTION_INDEX)
+                } else {
+                    (TEXT_SECTION_MIN_ADDRESS, TEXT_SECTION_INDEX)
+                };
+            Symbol {
+                name: label.name.clone(),
+                value: relative(label.address, base_address),
+                size: 0,
+                binding: STB_LOCAL,
+                symbol_type: STT_NOTYPE,
+                section_index,
+            }
+        })
+        .chain(
+            program
+                .data
+                .iter()
+                .filter(|datum| datum.is_named)
+                .map(|datum| {
+                    let (base_address, section_index) = if datum.is_kernel() {
+                        (KERNEL_DATA_SECTION_MIN_ADDRESS, KERNEL_DATA_SECTION_INDEX)
+                    } else {
+                        (DATA_SECTION_MIN_ADDRESS, DATA_SECTION_INDEX)
+                    };
+                    Symbol {
+                        name: datum.name.clone(),
+                        value: relative(datum.address, base_address),
+                        size: get_datum_size(&program.data, datum),
+                        binding: STB_LOCAL,
+                        symbol_type: STT_OBJECT,
+                        section_index,
+                    }
+                }),
+        )
+        .map(|symbol| Symbol {
+            binding: if program.is_global(&symbol.name) {
+                STB_GLOBAL
+            } else {
+                STB_LOCAL
+            },
+            ..symbol
+        })
+        .collect::<Vec<Symbol>>();
+
+    defined_symbols.sort_by_key(|symbol| symbol.binding);
+    symbols.extend(defined_symbols);
+    symbols.extend(program.get_externs().iter().map(|name| Symbol {
+        name: name.clone(),
+        value: 0,
+        size: 0,
+        binding: STB_GLOBAL,
+        symbol_type: STT_NOTYPE,
+        section_index: SHN_UNDEF,
+    }));
+
+    symbols
+}
+
+fn write_file(filepath: &str, bytes: &[u8]) {
+    let mut file = File::create(filepath).expect("Failed to crate output file.");
+    file.write_all(bytes).expect("Failed to write output file.");
+}
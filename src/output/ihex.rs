@@ -0,0 +1,75 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::options::Options;
+use crate::output::{collect_segments, resolve_entry_address, Output};
+use crate::Program;
+
+const RECORD_SIZE: usize = 16;
+
+const DATA_RECORD: u8 = 0x00;
+const END_OF_FILE_RECORD: u8 = 0x01;
+const EXTENDED_LINEAR_ADDRESS_RECORD: u8 = 0x04;
+const START_LINEAR_ADDRESS_RECORD: u8 = 0x05;
+
+pub struct Ihex;
+
+impl Output for Ihex {
+    fn write(&self, program: &Program, options: &Options) {
+        let mut records = vec![];
+        let mut upper_address: Option<u32> = None;
+
+        collect_segments(program, options.endianness)
+            .iter()
+            .for_each(|segment| {
+                segment
+                    .bytes
+                    .chunks(RECORD_SIZE)
+                    .enumerate()
+                    .for_each(|(index, chunk)| {
+                        let address = segment.address + (index * RECORD_SIZE) as u32;
+                        if upper_address != Some(address >> 16) {
+                            upper_address = Some(address >> 16);
+                            let upper = (address >> 16) as u16;
+                            records.push(compose_record(
+                                EXTENDED_LINEAR_ADDRESS_RECORD,
+                                0,
+                                &upper.to_be_bytes(),
+                            ));
+                        }
+                        records.push(compose_record(DATA_RECORD, address as u16, chunk));
+                    });
+            });
+
+        let entry = resolve_entry_address(program, options);
+        records.push(compose_record(
+            START_LINEAR_ADDRESS_RECORD,
+            0,
+            &entry.to_be_bytes(),
+        ));
+        records.push(compose_record(END_OF_FILE_RECORD, 0, &[]));
+
+        let mut file =
+            File::create(&options.output_filepath).expect("Failed to crate output file.");
+        write!(file, "{}", records.join("")).expect("Failed to write output file.");
+    }
+}
+
+fn compose_record(record_type: u8, address: u16, data: &[u8]) -> String {
+    let mut bytes = vec![data.len() as u8];
+    bytes.extend(&address.to_be_bytes());
+    bytes.push(record_type);
+    bytes.extend(data);
+
+    let checksum = bytes
+        .iter()
+        .fold(0u8, |sum, byte| sum.wrapping_add(*byte))
+        .wrapping_neg();
+    bytes.push(checksum);
+
+    let hex = bytes
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<String>();
+    format!(":{}\n", hex)
+}
@@ -0,0 +1,208 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::constants::{DATA_SECTION_MIN_ADDRESS, TEXT_SECTION_MIN_ADDRESS, WORD};
+use crate::instruction::{convert_opcode_to_format, InstructionFormat};
+use crate::options::Options;
+use crate::output::{resolve_entry_address, Output};
+use crate::symbol::collect_symbols;
+use crate::text::Text;
+use crate::Program;
+
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+pub struct Json;
+
+fn escape(text: &str) -> String {
+    let mut result = String::from("\"");
+    text.chars().for_each(|c| match c {
+        '"' => result.push_str("\\\""),
+        '\\' => result.push_str("\\\\"),
+        '\n' => result.push_str("\\n"),
+        '\t' => result.push_str("\\t"),
+        c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+        c => result.push(c),
+    });
+    result.push('"');
+    result
+}
+
+fn compose_optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| String::from("null"), |value| value.to_string())
+}
+
+fn compose_array(items: &[String]) -> String {
+    if items.is_empty() {
+        String::from("[]")
+    } else {
+        format!("[\n    {}\n  ]", items.join(",\n    "))
+    }
+}
+
+fn compose_section(name: &str, address: i32, words: &[u32]) -> String {
+    format!(
+        "{{\"name\": {}, \"address\": {}, \"size\": {}, \"words\": [{}]}}",
+        escape(name),
+        address as u32,
+        words.len() as i32 * WORD,
+        words
+            .iter()
+            .map(|word| word.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+fn compose_instruction(text: &Text, address: i32, input_filepath: &str) -> String {
+    let format = convert_opcode_to_format(text.opcode, text.rs);
+    let is_branch = text
+        .get_instruction()
+        .is_some_and(|instruction| instruction.is_branch());
+    let (format_name, rd, shamt, funct, immediate, target, target_address) = match format {
+        InstructionFormat::REGISTER => (
+            "R",
+            Some(text.rd & 0x1f),
+            Some(text.shamt & 0x1f),
+            Some(text.funct & 0x3f),
+            None,
+            None,
+            None,
+        ),
+        InstructionFormat::JUMP => {
+            let target = text.address & 0x3ffffff;
+            let target_address = ((address + WORD) & !0xfffffff) | (target << 2);
+            (
+                "J",
+                None,
+                None,
+                None,
+                None,
+                Some(target),
+                Some(target_address as u32),
+            )
+        }
+        _ => {
+            let immediate = text.immediate & 0xffff;
+            let target_address =
+                is_branch.then(|| (address + WORD + ((immediate as i16 as i32) << 2)) as u32);
+            ("I", None, None, None, Some(immediate), None, target_address)
+        }
+    };
+    let (rs, rt) = match format {
+        InstructionFormat::JUMP => (None, None),
+        _ => (Some(text.rs & 0x1f), Some(text.rt & 0x1f)),
+    };
+    let source = text.source.as_ref().map_or_else(
+        || String::from("null"),
+        |source| {
+            format!(
+                "{{\"file\": {}, \"line\": {}, \"text\": {}}}",
+                escape(input_filepath),
+                source.line_number,
+                escape(source.line.trim())
+            )
+        },
+    );
+    let pseudo_instruction = text
+        .source
+        .as_ref()
+        .and_then(|source| source.pseudo_instruction.as_ref())
+        .map(|name| escape(name));
+
+    format!(
+        "{{\"address\": {}, \"encoding\": {}, \"mnemonic\": {}, \"format\": \"{}\", \
+\"fields\": {{\"opcode\": {}, \"rs\": {}, \"rt\": {}, \"rd\": {}, \"shamt\": {}, \"funct\": {}, \
+\"immediate\": {}, \"target\": {}}}, \"target_address\": {}, \"source\": {}, \"pseudo\": {}}}",
+        address as u32,
+        text.to_word(),
+        compose_optional(
+            text.get_instruction()
+                .map(|instruction| escape(instruction.name))
+        ),
+        format_name,
+        text.opcode & 0x3f,
+        compose_optional(rs),
+        compose_optional(rt),
+        compose_optional(rd),
+        compose_optional(shamt),
+        compose_optional(funct),
+        compose_optional(immediate),
+        compose_optional(target),
+        compose_optional(target_address),
+        source,
+        compose_optional(pseudo_instruction),
+    )
+}
+
+impl Output for Json {
+    fn write(&self, program: &Program, options: &Options) {
+        let text_words = program
+            .texts
+            .iter()
+            .map(|text| text.to_word())
+            .collect::<Vec<u32>>();
+        let data_words = program
+            .data
+            .iter()
+            .map(|datum| datum.to_word(options.endianness))
+            .collect::<Vec<u32>>();
+        let sections = vec![
+            compose_section(".text", TEXT_SECTION_MIN_ADDRESS, &text_words),
+            compose_section(".data", DATA_SECTION_MIN_ADDRESS, &data_words),
+        ];
+
+        let instructions = program
+            .texts
+            .iter()
+            .enumerate()
+            .map(|(index, text)| {
+                let address = TEXT_SECTION_MIN_ADDRESS + index as i32 * WORD;
+                compose_instruction(text, address, &options.input_filepath)
+            })
+            .collect::<Vec<String>>();
+
+        let symbols = collect_symbols(program)
+            .iter()
+            .map(|symbol| {
+                format!(
+                    "{{\"name\": {}, \"address\": {}, \"size\": {}, \"section\": {}, \
+\"binding\": {}, \"line\": {}}}",
+                    escape(&symbol.name),
+                    symbol.address as u32,
+                    symbol.size,
+                    escape(symbol.section.to_name()),
+                    escape(symbol.binding.to_name()),
+                    compose_optional(symbol.line_number)
+                )
+            })
+            .collect::<Vec<String>>();
+
+        let warnings = program
+            .warnings
+            .iter()
+            .map(|warning| {
+                format!(
+                    "{{\"line\": {}, \"message\": {}}}",
+                    compose_optional(warning.line_number),
+                    escape(&warning.message)
+                )
+            })
+            .collect::<Vec<String>>();
+
+        let result = format!(
+            "{{\n  \"version\": {},\n  \"source\": {},\n  \"entry\": {},\n  \
+\"sections\": {},\n  \"instructions\": {},\n  \"symbols\": {},\n  \"warnings\": {}\n}}\n",
+            JSON_SCHEMA_VERSION,
+            escape(&options.input_filepath),
+            resolve_entry_address(program, options),
+            compose_array(&sections),
+            compose_array(&instructions),
+            compose_array(&symbols),
+            compose_array(&warnings),
+        );
+
+        let mut file =
+            File::create(&options.output_filepath).expect("Failed to create output file.");
+        write!(file, "{}", result).expect("Failed to write output file.");
+    }
+}
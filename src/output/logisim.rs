@@ -0,0 +1,63 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::options::Options;
+use crate::output::memory::collect_words;
+use crate::output::{collect_segments, compose_segment_filepath, Output};
+use crate::Program;
+
+const HEADER: &str = "v2.0 raw";
+const VALUES_PER_LINE: usize = 8;
+const MIN_RUN_LENGTH: usize = 4;
+
+pub struct Logisim;
+
+impl Output for Logisim {
+    fn write(&self, program: &Program, options: &Options) {
+        collect_segments(program, options.endianness)
+            .iter()
+            .for_each(|segment| {
+                let words = collect_words(segment, options);
+
+                if let Some(address_width) = options.address_width {
+                    if words.len() > 1 << address_width {
+                        panic!(
+                            "The {} section does not fit in the address width.",
+                            segment.name
+                        );
+                    }
+                }
+
+                let values = compress_runs(&words);
+                let lines = values
+                    .chunks(VALUES_PER_LINE)
+                    .map(|chunk| chunk.join(" "))
+                    .collect::<Vec<String>>();
+
+                let filepath = compose_segment_filepath(&options.output_filepath, segment);
+                let mut file = File::create(filepath).expect("Failed to crate output file.");
+                writeln!(file, "{}", HEADER).expect("Failed to write output file.");
+                lines.iter().for_each(|line| {
+                    writeln!(file, "{}", line).expect("Failed to write output file.");
+                });
+            });
+    }
+}
+
+fn compress_runs(words: &[u32]) -> Vec<String> {
+    let mut runs: Vec<(usize, u32)> = vec![];
+    words.iter().for_each(|word| match runs.last_mut() {
+        Some((count, value)) if value == word => *count += 1,
+        _ => runs.push((1, *word)),
+    });
+
+    runs.iter()
+        .flat_map(|(count, value)| {
+            if *count >= MIN_RUN_LENGTH {
+                vec![format!("{}*{:x}", count, value)]
+            } else {
+                vec![format!("{:x}", value); *count]
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,141 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::endianness::Endianness;
+use crate::options::{Fill, Options};
+use crate::output::{collect_segments, compose_segment_filepath, Output, Segment};
+use crate::text::get_text_from_code;
+use crate::Program;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum MemoryFormat {
+    READMEMH,
+    READMEMB,
+    COE,
+    MIF,
+}
+
+pub struct Memory {
+    format: MemoryFormat,
+}
+
+impl Memory {
+    pub fn new(format: MemoryFormat) -> Self {
+        Self { format }
+    }
+
+    fn compose_image(&self, words: &[u32], options: &Options) -> String {
+        let width = options.word_width;
+        let format_word = |word: u32| match self.format {
+            MemoryFormat::READMEMB => format!("{:0width$b}", word, width = width),
+            _ => format!("{:0width$x}", word, width = width / 4),
+        };
+
+        match self.format {
+            MemoryFormat::READMEMH | MemoryFormat::READMEMB => words
+                .iter()
+                .enumerate()
+                .map(|(index, word)| {
+                    if options.address_markers {
+                        format!("@{:08x} {}\n", index, format_word(*word))
+                    } else {
+                        format!("{}\n", format_word(*word))
+                    }
+                })
+                .collect(),
+            MemoryFormat::COE => format!(
+                "memory_initialization_radix=16;\nmemory_initialization_vector=\n{};\n",
+                words
+                    .iter()
+                    .map(|word| format_word(*word))
+                    .collect::<Vec<String>>()
+                    .join(",\n")
+            ),
+            MemoryFormat::MIF => {
+                let contents = compress_runs(words)
+                    .iter()
+                    .map(|(start, end, word)| {
+                        if start == end {
+                            format!("\t{:x} : {};\n", start, format_word(*word))
+                        } else {
+                            format!("\t[{:x}..{:x}] : {};\n", start, end, format_word(*word))
+                        }
+                    })
+                    .collect::<String>();
+                format!(
+                    "WIDTH={};\nDEPTH={};\n\nADDRESS_RADIX=HEX;\nDATA_RADIX=HEX;\n\nCONTENT BEGIN\n{}END;\n",
+                    width,
+                    words.len(),
+                    contents
+                )
+            }
+        }
+    }
+}
+
+impl Output for Memory {
+    fn write(&self, program: &Program, options: &Options) {
+        collect_segments(program, options.endianness)
+            .iter()
+            .for_each(|segment| {
+                let words = collect_words(segment, options);
+                let filepath = compose_segment_filepath(&options.output_filepath, segment);
+
+                let mut file = File::create(filepath).expect("Failed to crate output file.");
+                write!(file, "{}", self.compose_image(&words, options))
+                    .expect("Failed to write output file.");
+            });
+    }
+}
+
+pub fn collect_words(segment: &Segment, options: &Options) -> Vec<u32> {
+    let word_size = options.word_width / 8;
+    let mut words = vec![0; options.base_offset];
+    words.extend(
+        segment
+            .bytes
+            .chunks(word_size)
+            .map(|chunk| combine_bytes(chunk, options.endianness)),
+    );
+
+    if let Some(depth) = options.memory_depth {
+        if words.len() > depth {
+            panic!(
+                "The {} section does not fit in the memory depth.",
+                segment.name
+            );
+        }
+
+        let fill = match (segment.name, options.fill) {
+            ("text", Fill::NOP) => {
+                let nop = get_text_from_code("sll\t$0, $0, 0", 0, &[], &[], &[]).to_word();
+                let bytes = options.endianness.word_to_bytes(nop);
+                combine_bytes(&bytes[..word_size], options.endianness)
+            }
+            _ => 0,
+        };
+        words.resize(depth, fill);
+    }
+
+    words
+}
+
+fn combine_bytes(bytes: &[u8], endianness: Endianness) -> u32 {
+    let fold = |word: u32, byte: &u8| word << 8 | *byte as u32;
+    match endianness {
+        Endianness::BIG => bytes.iter().fold(0, fold),
+        Endianness::LITTLE => bytes.iter().rev().fold(0, fold),
+    }
+}
+
+fn compress_runs(words: &[u32]) -> Vec<(usize, usize, u32)> {
+    let mut runs: Vec<(usize, usize, u32)> = vec![];
+    words
+        .iter()
+        .enumerate()
+        .for_each(|(index, word)| match runs.last_mut() {
+            Some((_, end, value)) if value == word => *end = index,
+            _ => runs.push((index, index, *word)),
+        });
+    runs
+}
@@ -0,0 +1,62 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::options::Options;
+use crate::output::{collect_segments, resolve_entry_address, Output};
+use crate::Program;
+
+const RECORD_SIZE: usize = 16;
+const HEADER: &[u8] = b"mipssembler";
+
+pub struct Srec;
+
+impl Output for Srec {
+    fn write(&self, program: &Program, options: &Options) {
+        let mut records = vec![compose_record('0', &[0, 0], HEADER)];
+        let mut data_record_count = 0;
+
+        collect_segments(program, options.endianness)
+            .iter()
+            .for_each(|segment| {
+                segment
+                    .bytes
+                    .chunks(RECORD_SIZE)
+                    .enumerate()
+                    .for_each(|(index, chunk)| {
+                        let address = segment.address + (index * RECORD_SIZE) as u32;
+                        records.push(compose_record('3', &address.to_be_bytes(), chunk));
+                        data_record_count += 1;
+                    });
+            });
+
+        if data_record_count <= 0xffff {
+            records.push(compose_record(
+                '5',
+                &(data_record_count as u16).to_be_bytes(),
+                &[],
+            ));
+        }
+
+        let entry = resolve_entry_address(program, options);
+        records.push(compose_record('7', &entry.to_be_bytes(), &[]));
+
+        let mut file =
+            File::create(&options.output_filepath).expect("Failed to crate output file.");
+        write!(file, "{}", records.join("")).expect("Failed to write output file.");
+    }
+}
+
+fn compose_record(record_type: char, address: &[u8], data: &[u8]) -> String {
+    let mut bytes = vec![(address.len() + data.len() + 1) as u8];
+    bytes.extend(address);
+    bytes.extend(data);
+
+    let checksum = !bytes.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+    bytes.push(checksum);
+
+    let hex = bytes
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<String>();
+    format!("S{}{}\n", record_type, hex)
+}
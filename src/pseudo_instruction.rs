@@ -1,14 +1,17 @@
-use crate::constants::DATA_SECTION_MIN_ADDRESS;
 use crate::datum::{find_datum, Datum};
 
-pub fn disassemble_pseudo_instruction(code: &str, data: &[Datum]) -> Option<Vec<String>> {
-    if let [name, arguments] = code.trim_start().split('\t').collect::<Vec<&str>>()[..] {
-        match name {
-            "la" => Some(la(arguments, &data)),
-            _ => None,
-        }
-    } else {
-        panic!("Invalid instruction.")
+pub fn disassemble_pseudo_instruction(
+    code: &str,
+    data: &[Datum],
+    relocatable: bool,
+) -> Option<Vec<String>> {
+    match code.trim_start().split('\t').collect::<Vec<&str>>()[..] {
+        ["la", arguments] if relocatable => Some(relocatable_la(arguments)),
+        ["la", arguments] => Some(la(arguments, data)),
+        ["move", arguments] => Some(move_register(arguments)),
+        ["nop"] => Some(vec![String::from("sll\t$0, $0, 0")]),
+        [_] | [_, _] => None,
+        _ => panic!("Invalid instruction."),
     }
 }
 
@@ -20,12 +23,12 @@ fn la(arguments: &str, data: &[Datum]) -> Vec<String> {
         .collect::<Vec<&str>>();
 
     if let [register, datum_name] = argument_text[..] {
-        if let Some(datum) = find_datum(datum_name, &data) {
-            let shifted_datum_address = datum.address >> 16;
+        if let Some(datum) = find_datum(datum_name, data) {
+            let shifted_datum_address = datum.address as u32 >> 16;
             result.push(format!("lui\t{}, {}", register, shifted_datum_address));
 
-            if (datum.address << 16) > 0 {
-                let address = datum.address - DATA_SECTION_MIN_ADDRESS;
+            let address = datum.address & 0xffff;
+            if address != 0 {
                 result.push(format!("ori\t{}, {}, {}", register, register, address));
             }
         } else {
@@ -37,3 +40,32 @@ fn la(arguments: &str, data: &[Datum]) -> Vec<String> {
 
     result
 }
+
+fn relocatable_la(arguments: &str) -> Vec<String> {
+    let argument_text = arguments
+        .split(',')
+        .map(|arg| arg.trim())
+        .collect::<Vec<&str>>();
+
+    if let [register, symbol] = argument_text[..] {
+        vec![
+            format!("lui\t{}, %hi({})", register, symbol),
+            format!("addiu\t{}, {}, %lo({})", register, register, symbol),
+        ]
+    } else {
+        panic!("Failed to parse arguments.");
+    }
+}
+
+fn move_register(arguments: &str) -> Vec<String> {
+    let argument_text = arguments
+        .split(',')
+        .map(|arg| arg.trim())
+        .collect::<Vec<&str>>();
+
+    if let [destination, source] = argument_text[..] {
+        vec![format!("addu\t{}, {}, $0", destination, source)]
+    } else {
+        panic!("Failed to parse arguments.");
+    }
+}
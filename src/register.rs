@@ -0,0 +1,58 @@
+pub const REGISTER_NAMES: [&str; 32] = [
+    "zero", "at", "v0", "v1", "a0", "a1", "a2", "a3", "t0", "t1", "t2", "t3", "t4", "t5", "t6",
+    "t7", "s0", "s1", "s2", "s3", "s4", "s5", "s6", "s7", "t8", "t9", "k0", "k1", "gp", "sp", "fp",
+    "ra",
+];
+
+pub const HI_REGISTER: i32 = 32;
+pub const LO_REGISTER: i32 = 33;
+pub const CONDITION_FLAG_REGISTER: i32 = 34;
+pub const FLOAT_REGISTER_BASE: i32 = 64;
+
+pub fn find_register(text: &str) -> Option<i32> {
+    let name = text.strip_prefix('$')?;
+    if let Ok(number) = name.parse::<i32>() {
+        return (0..32).contains(&number).then_some(number);
+    }
+    match name {
+        "s8" => Some(30),
+        _ => REGISTER_NAMES
+            .iter()
+            .position(|register_name| *register_name == name)
+            .map(|number| number as i32),
+    }
+}
+
+pub fn find_float_register(text: &str) -> Option<i32> {
+    let number = text.strip_prefix("$f")?.parse::<i32>().ok()?;
+    (0..32).contains(&number).then_some(number)
+}
+
+pub fn resolve_register(text: &str, is_float: bool) -> i32 {
+    text.strip_prefix('$').expect("Invalid register.");
+    match (find_register(text), find_float_register(text)) {
+        (Some(number), None) if !is_float => number,
+        (None, Some(number)) if is_float => number,
+        (Some(_), None) => panic!("Expected a floating-point register: {}", text),
+        (None, Some(_)) => panic!("Expected a general-purpose register: {}", text),
+        _ => panic!("Unknown register."),
+    }
+}
+
+pub fn get_register_name(number: i32) -> String {
+    format!("${}", REGISTER_NAMES[(number & 0x1f) as usize])
+}
+
+pub fn get_float_register_name(number: i32) -> String {
+    format!("$f{}", number & 0x1f)
+}
+
+pub fn get_register_label(number: i32) -> String {
+    match number {
+        HI_REGISTER => String::from("hi"),
+        LO_REGISTER => String::from("lo"),
+        CONDITION_FLAG_REGISTER => String::from("fcc"),
+        FLOAT_REGISTER_BASE.. => get_float_register_name(number),
+        _ => get_register_name(number),
+    }
+}
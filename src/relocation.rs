@@ -0,0 +1,44 @@
+#[derive(Clone, Copy, PartialEq)]
+pub enum RelocationType {
+    WORD32,
+    JUMP26,
+    HI16,
+    LO16,
+    PC16,
+}
+
+impl RelocationType {
+    pub fn to_code(self) -> u8 {
+        match self {
+            RelocationType::WORD32 => 2,
+            RelocationType::JUMP26 => 4,
+            RelocationType::HI16 => 5,
+            RelocationType::LO16 => 6,
+            RelocationType::PC16 => 10,
+        }
+    }
+
+    pub fn to_addend_word(self, word: u32) -> u32 {
+        match self {
+            RelocationType::WORD32 => 0,
+            RelocationType::JUMP26 => word & !0x03ff_ffff,
+            RelocationType::HI16 | RelocationType::LO16 => word & !0xffff,
+            RelocationType::PC16 => (word & !0xffff) | 0xffff,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Relocation {
+    pub relocation_type: RelocationType,
+    pub symbol: String,
+}
+
+impl Relocation {
+    pub fn new(relocation_type: RelocationType, symbol: &str) -> Self {
+        Self {
+            relocation_type,
+            symbol: symbol.to_string(),
+        }
+    }
+}
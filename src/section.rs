@@ -3,12 +3,28 @@ pub enum Section {
     NONE,
     DATA,
     TEXT,
+    KDATA,
+    KTEXT,
+}
+
+impl Section {
+    pub fn to_name(&self) -> &'static str {
+        match self {
+            Section::NONE => "*UND*",
+            Section::DATA => ".data",
+            Section::TEXT => ".text",
+            Section::KDATA => ".kdata",
+            Section::KTEXT => ".ktext",
+        }
+    }
 }
 
 pub fn resolve_section(code: &str) -> Option<Section> {
     match code {
         "\t.data" => Some(Section::DATA),
         "\t.text" => Some(Section::TEXT),
+        "\t.kdata" => Some(Section::KDATA),
+        "\t.ktext" => Some(Section::KTEXT),
         _ => None,
     }
 }
@@ -0,0 +1,513 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use crate::constants::{KERNEL_TEXT_SECTION_MIN_ADDRESS, TEXT_SECTION_MIN_ADDRESS, WORD};
+use crate::endianness::Endianness;
+use crate::register::REGISTER_NAMES;
+use crate::text::Text;
+use crate::Program;
+
+pub mod cache;
+pub mod coprocessor0;
+pub mod coprocessor1;
+pub mod device;
+pub mod memory;
+pub mod pipeline;
+pub mod syscall;
+
+use crate::simulator::cache::{AccessKind, Caches};
+use crate::simulator::coprocessor0::{
+    BAD_ADDRESS_REGISTER, CAUSE_REGISTER, EPC_REGISTER, INITIAL_STATUS, STATUS_REGISTER,
+};
+use crate::simulator::device::Device;
+use crate::simulator::memory::Memory;
+
+pub const STACK_POINTER: u32 = 0x7ffffffc;
+pub const GLOBAL_POINTER: u32 = 0x10008000;
+pub const HEAP_ADDRESS: u32 = 0x10040000;
+
+const FIRST_FILE_DESCRIPTOR: u32 = 3;
+
+const GLOBAL_POINTER_REGISTER: usize = 28;
+const STACK_POINTER_REGISTER: usize = 29;
+const RETURN_ADDRESS_REGISTER: usize = 31;
+
+fn get_size_mask(size: u32) -> u32 {
+    match size {
+        1 => 0xff,
+        2 => 0xffff,
+        _ => 0xffff_ffff,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Exception {
+    ADEL(u32),
+    ADES(u32),
+    SYS,
+    BP,
+    RI(u32),
+    OV,
+}
+
+impl Exception {
+    pub fn to_code(self) -> u32 {
+        match self {
+            Exception::ADEL(_) => 4,
+            Exception::ADES(_) => 5,
+            Exception::SYS => 8,
+            Exception::BP => 9,
+            Exception::RI(_) => 10,
+            Exception::OV => 12,
+        }
+    }
+
+    pub fn describe(self) -> String {
+        match self {
+            Exception::ADEL(address) => format!("address error on load from 0x{:08x}", address),
+            Exception::ADES(address) => format!("address error on store to 0x{:08x}", address),
+            Exception::SYS => String::from("unsupported syscall"),
+            Exception::BP => String::from("breakpoint"),
+            Exception::RI(word) => format!("reserved instruction 0x{:08x}", word),
+            Exception::OV => String::from("arithmetic overflow"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum StopReason {
+    END,
+    EXIT(i32),
+    EXCEPTION(Exception),
+    LIMIT,
+}
+
+pub struct Simulator {
+    pub registers: [u32; 32],
+    pub pc: u32,
+    pub hi: u32,
+    pub lo: u32,
+    pub coprocessor0: [u32; 32],
+    pub coprocessor1: [u32; 32],
+    pub condition_flag: bool,
+    pub floating_point: bool,
+    pub memory: Memory,
+    pub text_end_address: u32,
+    pub kernel_text_end_address: u32,
+    pub steps: u64,
+    pub heap_address: u32,
+    pub exit_code: Option<i32>,
+    pub sandbox: Option<PathBuf>,
+    pub input: Box<dyn BufRead>,
+    pub output: Box<dyn Write>,
+    pub delay_slots: bool,
+    pub delayed_target: Option<u32>,
+    pub caches: Option<Caches>,
+    pub devices: Vec<Box<dyn Device>>,
+    files: BTreeMap<u32, File>,
+    next_descriptor: u32,
+}
+
+impl Simulator {
+    pub fn new(program: &Program, entry: u32, endianness: Endianness) -> Self {
+        let mut memory = Memory::new(endianness);
+        program.texts.iter().enumerate().for_each(|(index, text)| {
+            let address = TEXT_SECTION_MIN_ADDRESS + index as i32 * WORD;
+            memory.write_word(address as u32, text.to_word());
+        });
+        program
+            .kernel_texts
+            .iter()
+            .enumerate()
+            .for_each(|(index, text)| {
+                let address = KERNEL_TEXT_SECTION_MIN_ADDRESS + index as i32 * WORD;
+                memory.write_word(address as u32, text.to_word());
+            });
+        program
+            .data
+            .iter()
+            .for_each(|datum| memory.write_word(datum.address as u32, datum.to_word(endianness)));
+
+        let data_end_address = program
+            .data
+            .iter()
+            .map(|datum| datum.address as u32 + WORD as u32)
+            .max()
+            .unwrap_or_default();
+
+        let mut registers = [0; 32];
+        registers[GLOBAL_POINTER_REGISTER] = GLOBAL_POINTER;
+        registers[STACK_POINTER_REGISTER] = STACK_POINTER;
+
+        let mut coprocessor0 = [0; 32];
+        coprocessor0[STATUS_REGISTER] = INITIAL_STATUS;
+
+        Self {
+            registers,
+            pc: entry,
+            hi: 0,
+            lo: 0,
+            coprocessor0,
+            coprocessor1: [0; 32],
+            condition_flag: false,
+            floating_point: program.has_floating_point(),
+            memory,
+            text_end_address: (TEXT_SECTION_MIN_ADDRESS + program.texts.len() as i32 * WORD) as u32,
+            kernel_text_end_address: (KERNEL_TEXT_SECTION_MIN_ADDRESS
+                + program.kernel_texts.len() as i32 * WORD)
+                as u32,
+            steps: 0,
+            heap_address: HEAP_ADDRESS.max(data_end_address),
+            exit_code: None,
+            sandbox: None,
+            input: Box::new(BufReader::new(io::stdin())),
+            output: Box::new(io::stdout()),
+            delay_slots: program.delay_slots,
+            delayed_target: None,
+            caches: None,
+            devices: vec![],
+            files: BTreeMap::new(),
+            next_descriptor: FIRST_FILE_DESCRIPTOR,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.pc == self.text_end_address
+    }
+
+    fn set_register(&mut self, register: i32, value: u32) {
+        if register != 0 {
+            self.registers[register as usize] = value;
+        }
+    }
+
+    fn fetch(&self) -> Result<u32, Exception> {
+        let is_text = (TEXT_SECTION_MIN_ADDRESS as u32..self.text_end_address).contains(&self.pc);
+        let is_kernel_text = (KERNEL_TEXT_SECTION_MIN_ADDRESS as u32..self.kernel_text_end_address)
+            .contains(&self.pc);
+        if !(is_text || is_kernel_text) || !self.pc.is_multiple_of(WORD as u32) {
+            return Err(Exception::ADEL(self.pc));
+        }
+        Ok(self.memory.read_word(self.pc))
+    }
+
+    fn access_cache(&mut self, kind: AccessKind, address: u32) {
+        if let Some(caches) = self.caches.as_mut() {
+            caches.access(kind, address);
+        }
+    }
+
+    pub fn get_interrupts(&self) -> u32 {
+        self.devices
+            .iter()
+            .fold(0, |interrupts, device| interrupts | device.get_interrupts())
+    }
+
+    pub fn attach_device(&mut self, device: Box<dyn Device>) {
+        self.heap_address = self.heap_address.max(device.get_memory_range().end);
+        self.devices.push(device);
+    }
+
+    pub fn finish_devices(&mut self) {
+        let memory = &self.memory;
+        self.devices
+            .iter_mut()
+            .for_each(|device| device.finish(memory));
+    }
+
+    fn load(&mut self, address: u32, size: u32) -> Result<u32, Exception> {
+        if !address.is_multiple_of(size) {
+            return Err(Exception::ADEL(address));
+        }
+        let device = self
+            .devices
+            .iter_mut()
+            .find(|device| device.get_range().contains(&address));
+        if let Some(device) = device {
+            let value = device.load(address & !(WORD as u32 - 1), &mut *self.input);
+            return Ok(value & get_size_mask(size));
+        }
+        self.access_cache(AccessKind::LOAD, address);
+        Ok(match size {
+            1 => self.memory.read_byte(address) as u32,
+            2 => self.memory.read_half(address) as u32,
+            _ => self.memory.read_word(address),
+        })
+    }
+
+    fn store(&mut self, address: u32, size: u32, value: u32) -> Result<(), Exception> {
+        if !address.is_multiple_of(size) {
+            return Err(Exception::ADES(address));
+        }
+        let device = self
+            .devices
+            .iter_mut()
+            .find(|device| device.get_range().contains(&address));
+        if let Some(device) = device {
+            let address = address & !(WORD as u32 - 1);
+            device.store(address, value & get_size_mask(size), &mut *self.output);
+            return Ok(());
+        }
+        self.access_cache(AccessKind::STORE, address);
+        match size {
+            1 => self.memory.write_byte(address, value as u8),
+            2 => self.memory.write_half(address, value as u16),
+            _ => self.memory.write_word(address, value),
+        }
+        Ok(())
+    }
+
+    pub fn step(&mut self) -> Result<(), Exception> {
+        self.take_interrupt();
+        let delayed_target = self.delayed_target.take();
+        let result = self.fetch().and_then(|word| {
+            self.access_cache(AccessKind::FETCH, self.pc);
+            self.execute(word)
+        });
+        match result {
+            Ok(()) => {
+                if let Some(delayed_target) = delayed_target {
+                    self.pc = delayed_target;
+                }
+            }
+            Err(exception) => self.raise_exception(exception, delayed_target)?,
+        }
+        let input = &mut *self.input;
+        self.devices
+            .iter_mut()
+            .for_each(|device| device.tick(&mut *input));
+        self.steps += 1;
+        Ok(())
+    }
+
+    pub fn check_stop(&self, max_steps: Option<u64>) -> Option<StopReason> {
+        if let Some(exit_code) = self.exit_code {
+            Some(StopReason::EXIT(exit_code))
+        } else if self.is_finished() {
+            Some(StopReason::END)
+        } else if max_steps.is_some_and(|max_steps| self.steps >= max_steps) {
+            Some(StopReason::LIMIT)
+        } else {
+            None
+        }
+    }
+
+    pub fn run(&mut self, max_steps: Option<u64>) -> StopReason {
+        let stop_reason = loop {
+            if let Some(stop_reason) = self.check_stop(max_steps) {
+                break stop_reason;
+            }
+            if let Err(exception) = self.step() {
+                break StopReason::EXCEPTION(exception);
+            }
+        };
+        self.output.flush().expect("Failed to write output.");
+        stop_reason
+    }
+
+    fn execute(&mut self, word: u32) -> Result<(), Exception> {
+        let text = Text::from_word(word);
+        let instruction = text.get_instruction().ok_or(Exception::RI(word))?;
+
+        let rs = self.registers[text.rs as usize];
+        let rt = self.registers[text.rt as usize];
+        let shamt = text.shamt as u32;
+        let immediate = text.immediate as i16 as i32 as u32;
+        let zero_extended_immediate = text.immediate as u32;
+        let next_pc = self.pc.wrapping_add(WORD as u32);
+        let branch_target = next_pc.wrapping_add(immediate << 2);
+        let jump_target = (next_pc & 0xf000_0000) | ((text.address as u32) << 2);
+        let return_address = if self.delay_slots {
+            next_pc.wrapping_add(WORD as u32)
+        } else {
+            next_pc
+        };
+        let mut target = None;
+
+        match instruction.name {
+            "add" => {
+                let value = (rs as i32).checked_add(rt as i32).ok_or(Exception::OV)?;
+                self.set_register(text.rd, value as u32);
+            }
+            "addu" => self.set_register(text.rd, rs.wrapping_add(rt)),
+            "sub" => {
+                let value = (rs as i32).checked_sub(rt as i32).ok_or(Exception::OV)?;
+                self.set_register(text.rd, value as u32);
+            }
+            "subu" => self.set_register(text.rd, rs.wrapping_sub(rt)),
+            "and" => self.set_register(text.rd, rs & rt),
+            "or" => self.set_register(text.rd, rs | rt),
+            "xor" => self.set_register(text.rd, rs ^ rt),
+            "nor" => self.set_register(text.rd, !(rs | rt)),
+            "slt" => self.set_register(text.rd, ((rs as i32) < (rt as i32)) as u32),
+            "sltu" => self.set_register(text.rd, (rs < rt) as u32),
+            "sll" => self.set_register(text.rd, rt << shamt),
+            "srl" => self.set_register(text.rd, rt >> shamt),
+            "sra" => self.set_register(text.rd, ((rt as i32) >> shamt) as u32),
+            "sllv" => self.set_register(text.rd, rt << (rs & 0x1f)),
+            "srlv" => self.set_register(text.rd, rt >> (rs & 0x1f)),
+            "srav" => self.set_register(text.rd, ((rt as i32) >> (rs & 0x1f)) as u32),
+            "jr" => target = Some(rs),
+            "jalr" => {
+                self.set_register(text.rd, return_address);
+                target = Some(rs);
+            }
+            "syscall" => self.syscall()?,
+            "break" => return Err(Exception::BP),
+            "mfc0" => self.set_register(text.rt, self.coprocessor0[text.rd as usize]),
+            "mtc0" => self.coprocessor0[text.rd as usize] = rt,
+            "eret" => {
+                self.return_from_exception();
+                return Ok(());
+            }
+            "mfhi" => self.set_register(text.rd, self.hi),
+            "mthi" => self.hi = rs,
+            "mflo" => self.set_register(text.rd, self.lo),
+            "mtlo" => self.lo = rs,
+            "mult" => {
+                let product = (rs as i32 as i64) * (rt as i32 as i64);
+                self.hi = (product >> 32) as u32;
+                self.lo = product as u32;
+            }
+            "multu" => {
+                let product = (rs as u64) * (rt as u64);
+                self.hi = (product >> 32) as u32;
+                self.lo = product as u32;
+            }
+            "div" => {
+                if rt != 0 {
+                    self.lo = (rs as i32).wrapping_div(rt as i32) as u32;
+                    self.hi = (rs as i32).wrapping_rem(rt as i32) as u32;
+                }
+            }
+            "divu" => {
+                if let (Some(quotient), Some(remainder)) = (rs.checked_div(rt), rs.checked_rem(rt))
+                {
+                    self.lo = quotient;
+                    self.hi = remainder;
+                }
+            }
+            "addi" => {
+                let value = (rs as i32)
+                    .checked_add(immediate as i32)
+                    .ok_or(Exception::OV)?;
+                self.set_register(text.rt, value as u32);
+            }
+            "addiu" => self.set_register(text.rt, rs.wrapping_add(immediate)),
+            "slti" => self.set_register(text.rt, ((rs as i32) < (immediate as i32)) as u32),
+            "sltiu" => self.set_register(text.rt, (rs < immediate) as u32),
+            "andi" => self.set_register(text.rt, rs & zero_extended_immediate),
+            "ori" => self.set_register(text.rt, rs | zero_extended_immediate),
+            "xori" => self.set_register(text.rt, rs ^ zero_extended_immediate),
+            "lui" => self.set_register(text.rt, zero_extended_immediate << 16),
+            "lb" => {
+                let value = self.load(rs.wrapping_add(immediate), 1)?;
+                self.set_register(text.rt, value as i8 as i32 as u32);
+            }
+            "lbu" => {
+                let value = self.load(rs.wrapping_add(immediate), 1)?;
+                self.set_register(text.rt, value);
+            }
+            "lh" => {
+                let value = self.load(rs.wrapping_add(immediate), 2)?;
+                self.set_register(text.rt, value as i16 as i32 as u32);
+            }
+            "lhu" => {
+                let value = self.load(rs.wrapping_add(immediate), 2)?;
+                self.set_register(text.rt, value);
+            }
+            "lw" => {
+                let value = self.load(rs.wrapping_add(immediate), 4)?;
+                self.set_register(text.rt, value);
+            }
+            "sb" => self.store(rs.wrapping_add(immediate), 1, rt)?,
+            "sh" => self.store(rs.wrapping_add(immediate), 2, rt)?,
+            "sw" => self.store(rs.wrapping_add(immediate), 4, rt)?,
+            "beq" => target = (rs == rt).then_some(branch_target),
+            "bne" => target = (rs != rt).then_some(branch_target),
+            "blez" => target = ((rs as i32) <= 0).then_some(branch_target),
+            "bgtz" => target = ((rs as i32) > 0).then_some(branch_target),
+            "j" => target = Some(jump_target),
+            "jal" => {
+                self.set_register(RETURN_ADDRESS_REGISTER as i32, return_address);
+                target = Some(jump_target);
+            }
+            _ if instruction.is_float() => target = self.execute_coprocessor1(&text, word)?,
+            _ => return Err(Exception::RI(word)),
+        }
+
+        if self.delay_slots {
+            self.pc = next_pc;
+            self.delayed_target = target;
+        } else {
+            self.pc = target.unwrap_or(next_pc);
+        }
+        Ok(())
+    }
+
+    pub fn describe_stop_reason(&self, stop_reason: StopReason) -> String {
+        match stop_reason {
+            StopReason::END => format!("Program finished after {} steps.", self.steps),
+            StopReason::EXIT(exit_code) => format!(
+                "Program exited with code {} after {} steps.",
+                exit_code, self.steps
+            ),
+            StopReason::EXCEPTION(exception) => {
+                format!("Exception at 0x{:08x}: {}.", self.pc, exception.describe())
+            }
+            StopReason::LIMIT => format!("Step limit of {} reached.", self.steps),
+        }
+    }
+
+    pub fn format_registers(&self) -> String {
+        let mut result = format!(
+            "pc    = 0x{:08x}  hi    = 0x{:08x}  lo    = 0x{:08x}\n",
+            self.pc, self.hi, self.lo
+        );
+        if self.has_exception_handler() {
+            result.push_str(&format!(
+                "status = 0x{:08x}  cause = 0x{:08x}  epc = 0x{:08x}  vaddr = 0x{:08x}\n",
+                self.coprocessor0[STATUS_REGISTER],
+                self.coprocessor0[CAUSE_REGISTER],
+                self.coprocessor0[EPC_REGISTER],
+                self.coprocessor0[BAD_ADDRESS_REGISTER]
+            ));
+        }
+        self.registers
+            .chunks(4)
+            .enumerate()
+            .for_each(|(row, registers)| {
+                let line = registers
+                    .iter()
+                    .enumerate()
+                    .map(|(column, value)| {
+                        let name = format!("${}", REGISTER_NAMES[row * 4 + column]);
+                        format!("{:<5} = 0x{:08x}", name, value)
+                    })
+                    .collect::<Vec<String>>()
+                    .join("  ");
+                result.push_str(&format!("{}\n", line));
+            });
+        if self.floating_point {
+            self.coprocessor1
+                .chunks(4)
+                .enumerate()
+                .for_each(|(row, registers)| {
+                    let line = registers
+                        .iter()
+                        .enumerate()
+                        .map(|(column, value)| {
+                            let name = format!("$f{}", row * 4 + column);
+                            format!("{:<5} = 0x{:08x}", name, value)
+                        })
+                        .collect::<Vec<String>>()
+                        .join("  ");
+                    result.push_str(&format!("{}\n", line));
+                });
+            result.push_str(&format!("fcc   = {}\n", self.condition_flag as u32));
+        }
+        result
+    }
+}
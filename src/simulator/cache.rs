@@ -0,0 +1,364 @@
+use std::io::Write;
+
+use crate::constants::WORD;
+
+const RANDOM_SEED: u32 = 0x2545_f491;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ReplacementPolicy {
+    LRU,
+    FIFO,
+    RANDOM,
+}
+
+impl ReplacementPolicy {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "lru" => Some(ReplacementPolicy::LRU),
+            "fifo" => Some(ReplacementPolicy::FIFO),
+            "random" => Some(ReplacementPolicy::RANDOM),
+            _ => None,
+        }
+    }
+
+    fn get_name(self) -> &'static str {
+        match self {
+            ReplacementPolicy::LRU => "LRU",
+            ReplacementPolicy::FIFO => "FIFO",
+            ReplacementPolicy::RANDOM => "random",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum WritePolicy {
+    WRITEBACK,
+    WRITETHROUGH,
+}
+
+impl WritePolicy {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "back" => Some(WritePolicy::WRITEBACK),
+            "through" => Some(WritePolicy::WRITETHROUGH),
+            _ => None,
+        }
+    }
+
+    fn get_name(self) -> &'static str {
+        match self {
+            WritePolicy::WRITEBACK => "write-back",
+            WritePolicy::WRITETHROUGH => "write-through",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum AccessKind {
+    FETCH,
+    LOAD,
+    STORE,
+}
+
+impl AccessKind {
+    fn get_name(self) -> &'static str {
+        match self {
+            AccessKind::FETCH => "fetch",
+            AccessKind::LOAD => "load",
+            AccessKind::STORE => "store",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct CacheConfig {
+    pub size: u32,
+    pub block_size: u32,
+    pub associativity: u32,
+    pub replacement: ReplacementPolicy,
+    pub write_policy: WritePolicy,
+}
+
+impl CacheConfig {
+    pub fn parse(text: &str) -> Self {
+        let mut config = Self {
+            size: 1024,
+            block_size: 16,
+            associativity: 1,
+            replacement: ReplacementPolicy::LRU,
+            write_policy: WritePolicy::WRITEBACK,
+        };
+        let mut is_fully_associative = false;
+        text.split(',')
+            .filter(|setting| !setting.is_empty())
+            .for_each(|setting| {
+                let (key, value) = setting.split_once('=').expect("Invalid cache setting.");
+                match key {
+                    "size" => config.size = value.parse().expect("Invalid cache size."),
+                    "block" => {
+                        config.block_size = value.parse().expect("Invalid cache block size.")
+                    }
+                    "ways" if value == "full" => is_fully_associative = true,
+                    "ways" => config.associativity = value.parse().expect("Invalid cache ways."),
+                    "replacement" => {
+                        config.replacement = ReplacementPolicy::from_name(value)
+                            .expect("Unknown replacement policy.")
+                    }
+                    "write" => {
+                        config.write_policy =
+                            WritePolicy::from_name(value).expect("Unknown write policy.")
+                    }
+                    _ => panic!("Unknown cache setting."),
+                }
+            });
+
+        if config.block_size < WORD as u32 || !config.block_size.is_power_of_two() {
+            panic!("Cache block size must be a power of two of at least 4 bytes.");
+        }
+        if config.size < config.block_size || !config.size.is_power_of_two() {
+            panic!("Cache size must be a power of two of at least one block.");
+        }
+        let blocks = config.size / config.block_size;
+        if is_fully_associative {
+            config.associativity = blocks;
+        }
+        if config.associativity == 0 || !blocks.is_multiple_of(config.associativity) {
+            panic!("Cache ways must divide the number of blocks.");
+        }
+        config
+    }
+
+    fn get_set_count(&self) -> u32 {
+        self.size / self.block_size / self.associativity
+    }
+
+    fn describe(&self) -> String {
+        let organization = if self.associativity == 1 {
+            String::from("direct-mapped")
+        } else if self.get_set_count() == 1 {
+            String::from("fully associative")
+        } else {
+            format!("{}-way", self.associativity)
+        };
+        format!(
+            "{} bytes, {}-byte blocks, {}, {}, {}",
+            self.size,
+            self.block_size,
+            organization,
+            self.replacement.get_name(),
+            self.write_policy.get_name()
+        )
+    }
+}
+
+struct Block {
+    tag: u32,
+    dirty: bool,
+    loaded: u64,
+    used: u64,
+}
+
+pub struct Access {
+    pub set: u32,
+    pub hit: bool,
+    pub evicted: Option<(u32, bool)>,
+}
+
+pub struct Cache {
+    pub name: &'static str,
+    pub config: CacheConfig,
+    sets: Vec<Vec<Block>>,
+    clock: u64,
+    random_state: u32,
+    pub reads: u64,
+    pub read_misses: u64,
+    pub writes: u64,
+    pub write_misses: u64,
+    pub memory_writes: u64,
+}
+
+fn format_rate(count: u64, total: u64) -> String {
+    if total == 0 {
+        String::from("0.00%")
+    } else {
+        format!("{:.2}%", count as f64 * 100.0 / total as f64)
+    }
+}
+
+impl Cache {
+    pub fn new(name: &'static str, config: CacheConfig) -> Self {
+        Self {
+            name,
+            config,
+            sets: (0..config.get_set_count()).map(|_| vec![]).collect(),
+            clock: 0,
+            random_state: RANDOM_SEED,
+            reads: 0,
+            read_misses: 0,
+            writes: 0,
+            write_misses: 0,
+            memory_writes: 0,
+        }
+    }
+
+    fn choose_victim(&mut self, set: usize) -> usize {
+        let blocks = &self.sets[set];
+        let oldest = |get_time: fn(&Block) -> u64| {
+            (0..blocks.len())
+                .min_by_key(|&index| get_time(&blocks[index]))
+                .unwrap_or(0)
+        };
+        match self.config.replacement {
+            ReplacementPolicy::LRU => oldest(|block| block.used),
+            ReplacementPolicy::FIFO => oldest(|block| block.loaded),
+            ReplacementPolicy::RANDOM => {
+                self.random_state ^= self.random_state << 13;
+                self.random_state ^= self.random_state >> 17;
+                self.random_state ^= self.random_state << 5;
+                self.random_state as usize % blocks.len()
+            }
+        }
+    }
+
+    pub fn access(&mut self, address: u32, is_write: bool) -> Access {
+        self.clock += 1;
+        let clock = self.clock;
+        let set_count = self.config.get_set_count();
+        let block_address = address / self.config.block_size;
+        let set = block_address % set_count;
+        let tag = block_address / set_count;
+        let is_write_back = self.config.write_policy == WritePolicy::WRITEBACK;
+
+        if is_write {
+            self.writes += 1;
+            if !is_write_back {
+                self.memory_writes += 1;
+            }
+        } else {
+            self.reads += 1;
+        }
+
+        let blocks = &mut self.sets[set as usize];
+        if let Some(block) = blocks.iter_mut().find(|block| block.tag == tag) {
+            block.used = clock;
+            block.dirty |= is_write && is_write_back;
+            return Access {
+                set,
+                hit: true,
+                evicted: None,
+            };
+        }
+
+        if is_write {
+            self.write_misses += 1;
+            if !is_write_back {
+                return Access {
+                    set,
+                    hit: false,
+                    evicted: None,
+                };
+            }
+        } else {
+            self.read_misses += 1;
+        }
+
+        let evicted = if blocks.len() == self.config.associativity as usize {
+            let victim = self.choose_victim(set as usize);
+            let block = self.sets[set as usize].remove(victim);
+            if block.dirty {
+                self.memory_writes += 1;
+            }
+            let address = (block.tag * set_count + set) * self.config.block_size;
+            Some((address, block.dirty))
+        } else {
+            None
+        };
+        self.sets[set as usize].push(Block {
+            tag,
+            dirty: is_write,
+            loaded: clock,
+            used: clock,
+        });
+        Access {
+            set,
+            hit: false,
+            evicted,
+        }
+    }
+
+    pub fn format_summary(&self) -> String {
+        let accesses = self.reads + self.writes;
+        let misses = self.read_misses + self.write_misses;
+        format!(
+            "{} cache: {}\nAccesses: {} ({} reads, {} writes)\nHits: {}, misses: {} ({} read, {} write)\nHit rate: {}, miss rate: {}\nMemory writes: {}\n",
+            self.name,
+            self.config.describe(),
+            accesses,
+            self.reads,
+            self.writes,
+            accesses - misses,
+            misses,
+            self.read_misses,
+            self.write_misses,
+            format_rate(accesses - misses, accesses),
+            format_rate(misses, accesses),
+            self.memory_writes
+        )
+    }
+}
+
+pub struct Caches {
+    pub instruction: Option<Cache>,
+    pub data: Option<Cache>,
+    pub unified: Option<Cache>,
+    pub trace: Option<Box<dyn Write>>,
+}
+
+impl Caches {
+    pub fn access(&mut self, kind: AccessKind, address: u32) {
+        let cache = if self.unified.is_some() {
+            self.unified.as_mut()
+        } else if kind == AccessKind::FETCH {
+            self.instruction.as_mut()
+        } else {
+            self.data.as_mut()
+        };
+        let cache = match cache {
+            Some(cache) => cache,
+            None => return,
+        };
+
+        let access = cache.access(address, kind == AccessKind::STORE);
+        if let Some(trace) = self.trace.as_mut() {
+            let mut line = format!(
+                "{:<3}  {:<5}  0x{:08x}  set {:<4}  {}",
+                cache.name,
+                kind.get_name(),
+                address,
+                access.set,
+                if access.hit { "hit" } else { "miss" }
+            );
+            if let Some((address, dirty)) = access.evicted {
+                line.push_str(&format!("  evict 0x{:08x}", address));
+                if dirty {
+                    line.push_str(" (dirty)");
+                }
+            }
+            writeln!(trace, "{}", line).expect("Failed to write cache trace.");
+        }
+    }
+
+    pub fn flush_trace(&mut self) {
+        if let Some(trace) = self.trace.as_mut() {
+            trace.flush().expect("Failed to write cache trace.");
+        }
+    }
+
+    pub fn format_summary(&self) -> String {
+        [&self.unified, &self.instruction, &self.data]
+            .iter()
+            .filter_map(|cache| cache.as_ref())
+            .map(|cache| cache.format_summary())
+            .collect()
+    }
+}
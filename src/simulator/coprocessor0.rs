@@ -0,0 +1,84 @@
+use crate::constants::{KERNEL_TEXT_SECTION_MIN_ADDRESS, WORD};
+use crate::simulator::{Exception, Simulator};
+
+pub const BAD_ADDRESS_REGISTER: usize = 8;
+pub const STATUS_REGISTER: usize = 12;
+pub const CAUSE_REGISTER: usize = 13;
+pub const EPC_REGISTER: usize = 14;
+
+pub const INITIAL_STATUS: u32 = 0x0000_ff11;
+
+const INTERRUPT_ENABLE: u32 = 1;
+const EXCEPTION_LEVEL: u32 = 1 << 1;
+const INTERRUPT_MASK: u32 = 0xff00;
+const EXCEPTION_CODE_MASK: u32 = 0x7c;
+const BRANCH_DELAY: u32 = 1 << 31;
+
+const INTERRUPT_CODE: u32 = 0;
+
+impl Simulator {
+    pub fn has_exception_handler(&self) -> bool {
+        self.kernel_text_end_address > KERNEL_TEXT_SECTION_MIN_ADDRESS as u32
+    }
+
+    fn is_exception_level(&self) -> bool {
+        self.coprocessor0[STATUS_REGISTER] & EXCEPTION_LEVEL != 0
+    }
+
+    fn enter_exception(&mut self, code: u32, is_delay_slot: bool) {
+        let mut cause = self.coprocessor0[CAUSE_REGISTER] & !(BRANCH_DELAY | EXCEPTION_CODE_MASK);
+        cause |= code << 2;
+        if is_delay_slot {
+            cause |= BRANCH_DELAY;
+            self.coprocessor0[EPC_REGISTER] = self.pc.wrapping_sub(WORD as u32);
+        } else {
+            self.coprocessor0[EPC_REGISTER] = self.pc;
+        }
+        self.coprocessor0[CAUSE_REGISTER] = cause;
+    }
+
+    fn jump_to_handler(&mut self) {
+        self.coprocessor0[STATUS_REGISTER] |= EXCEPTION_LEVEL;
+        self.pc = KERNEL_TEXT_SECTION_MIN_ADDRESS as u32;
+        self.delayed_target = None;
+    }
+
+    pub(super) fn take_interrupt(&mut self) {
+        let interrupts = self.get_interrupts() & INTERRUPT_MASK;
+        let cause = self.coprocessor0[CAUSE_REGISTER] & !INTERRUPT_MASK;
+        self.coprocessor0[CAUSE_REGISTER] = cause | interrupts;
+
+        let status = self.coprocessor0[STATUS_REGISTER];
+        if self.has_exception_handler()
+            && self.delayed_target.is_none()
+            && status & INTERRUPT_ENABLE != 0
+            && !self.is_exception_level()
+            && interrupts & status != 0
+        {
+            self.enter_exception(INTERRUPT_CODE, false);
+            self.jump_to_handler();
+        }
+    }
+
+    pub(super) fn raise_exception(
+        &mut self,
+        exception: Exception,
+        delayed_target: Option<u32>,
+    ) -> Result<(), Exception> {
+        if let Exception::ADEL(address) | Exception::ADES(address) = exception {
+            self.coprocessor0[BAD_ADDRESS_REGISTER] = address;
+        }
+        self.enter_exception(exception.to_code(), delayed_target.is_some());
+        if !self.has_exception_handler() || self.is_exception_level() {
+            self.delayed_target = delayed_target;
+            return Err(exception);
+        }
+        self.jump_to_handler();
+        Ok(())
+    }
+
+    pub(super) fn return_from_exception(&mut self) {
+        self.coprocessor0[STATUS_REGISTER] &= !EXCEPTION_LEVEL;
+        self.pc = self.coprocessor0[EPC_REGISTER];
+    }
+}
@@ -0,0 +1,148 @@
+use crate::constants::WORD;
+use crate::endianness::Endianness;
+use crate::simulator::{Exception, Simulator};
+use crate::text::Text;
+
+const DOUBLE_WORD: u32 = 8;
+
+fn convert_to_word(value: f64) -> u32 {
+    let value = value.round_ties_even();
+    if value.is_nan() || value < i32::MIN as f64 || value > i32::MAX as f64 {
+        i32::MAX as u32
+    } else {
+        value as i32 as u32
+    }
+}
+
+impl Simulator {
+    fn read_single(&self, register: i32) -> f32 {
+        f32::from_bits(self.coprocessor1[register as usize])
+    }
+
+    fn write_single(&mut self, register: i32, value: f32) {
+        self.coprocessor1[register as usize] = value.to_bits();
+    }
+
+    fn read_double(&self, register: i32) -> f64 {
+        let register = (register & !1) as usize;
+        let bits =
+            ((self.coprocessor1[register + 1] as u64) << 32) | self.coprocessor1[register] as u64;
+        f64::from_bits(bits)
+    }
+
+    fn write_double(&mut self, register: i32, value: f64) {
+        let register = (register & !1) as usize;
+        let bits = value.to_bits();
+        self.coprocessor1[register] = bits as u32;
+        self.coprocessor1[register + 1] = (bits >> 32) as u32;
+    }
+
+    fn get_double_word_registers(&self, register: i32) -> (usize, usize) {
+        let register = register as usize;
+        match self.memory.endianness {
+            Endianness::BIG => (register + 1, register),
+            Endianness::LITTLE => (register, register + 1),
+        }
+    }
+
+    fn read_operand(&self, register: i32, is_double: bool) -> f64 {
+        if is_double {
+            self.read_double(register)
+        } else {
+            self.read_single(register) as f64
+        }
+    }
+
+    fn write_result(&mut self, register: i32, value: f64, is_double: bool) {
+        if is_double {
+            self.write_double(register, value);
+        } else {
+            self.write_single(register, value as f32);
+        }
+    }
+
+    pub(super) fn execute_coprocessor1(
+        &mut self,
+        text: &Text,
+        word: u32,
+    ) -> Result<Option<u32>, Exception> {
+        let instruction = text.get_instruction().ok_or(Exception::RI(word))?;
+        let is_double = instruction.is_double();
+        let is_odd = |register: i32| register & 1 != 0;
+        let has_odd_pair = if instruction.is_float_load_store() {
+            is_double && is_odd(text.rt)
+        } else {
+            instruction.is_float_operation()
+                && ((is_double && (is_odd(text.rd) || is_odd(text.rt)))
+                    || (instruction.is_double_result() && is_odd(text.shamt)))
+        };
+        if has_odd_pair {
+            return Err(Exception::RI(word));
+        }
+
+        let rs = self.registers[text.rs as usize];
+        let address = rs.wrapping_add(text.immediate as i16 as i32 as u32);
+        let fs = self.read_operand(text.rd, is_double);
+        let ft = self.read_operand(text.rt, is_double);
+        let next_pc = self.pc.wrapping_add(WORD as u32);
+        let branch_target = next_pc.wrapping_add((text.immediate as i16 as i32 as u32) << 2);
+
+        match instruction.name {
+            "add.s" | "add.d" => self.write_result(text.shamt, fs + ft, is_double),
+            "sub.s" | "sub.d" => self.write_result(text.shamt, fs - ft, is_double),
+            "mul.s" | "mul.d" => self.write_result(text.shamt, fs * ft, is_double),
+            "div.s" | "div.d" => self.write_result(text.shamt, fs / ft, is_double),
+            "abs.s" | "abs.d" => self.write_result(text.shamt, fs.abs(), is_double),
+            "neg.s" | "neg.d" => self.write_result(text.shamt, -fs, is_double),
+            "mov.s" => self.coprocessor1[text.shamt as usize] = self.coprocessor1[text.rd as usize],
+            "mov.d" => {
+                let value = self.read_double(text.rd);
+                self.write_double(text.shamt, value);
+            }
+            "cvt.s.d" | "cvt.s.w" | "cvt.d.s" | "cvt.d.w" | "cvt.w.s" | "cvt.w.d" => {
+                let value = if instruction.name.ends_with(".w") {
+                    self.coprocessor1[text.rd as usize] as i32 as f64
+                } else {
+                    fs
+                };
+                match instruction.name {
+                    "cvt.s.d" | "cvt.s.w" => self.write_single(text.shamt, value as f32),
+                    "cvt.d.s" | "cvt.d.w" => self.write_double(text.shamt, value),
+                    _ => self.coprocessor1[text.shamt as usize] = convert_to_word(value),
+                }
+            }
+            "c.eq.s" | "c.eq.d" => self.condition_flag = fs == ft,
+            "c.lt.s" | "c.lt.d" => self.condition_flag = fs < ft,
+            "c.le.s" | "c.le.d" => self.condition_flag = fs <= ft,
+            "bc1t" => return Ok(self.condition_flag.then_some(branch_target)),
+            "bc1f" => return Ok((!self.condition_flag).then_some(branch_target)),
+            "mfc1" => self.set_register(text.rt, self.coprocessor1[text.rd as usize]),
+            "mtc1" => self.coprocessor1[text.rd as usize] = self.registers[text.rt as usize],
+            "lwc1" => self.coprocessor1[text.rt as usize] = self.load(address, WORD as u32)?,
+            "swc1" => self.store(address, WORD as u32, self.coprocessor1[text.rt as usize])?,
+            "ldc1" => {
+                if !address.is_multiple_of(DOUBLE_WORD) {
+                    return Err(Exception::ADEL(address));
+                }
+                let (first, second) = self.get_double_word_registers(text.rt);
+                self.coprocessor1[first] = self.load(address, WORD as u32)?;
+                self.coprocessor1[second] =
+                    self.load(address.wrapping_add(WORD as u32), WORD as u32)?;
+            }
+            "sdc1" => {
+                if !address.is_multiple_of(DOUBLE_WORD) {
+                    return Err(Exception::ADES(address));
+                }
+                let (first, second) = self.get_double_word_registers(text.rt);
+                self.store(address, WORD as u32, self.coprocessor1[first])?;
+                self.store(
+                    address.wrapping_add(WORD as u32),
+                    WORD as u32,
+                    self.coprocessor1[second],
+                )?;
+            }
+            _ => return Err(Exception::RI(word)),
+        }
+        Ok(None)
+    }
+}
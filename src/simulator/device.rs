@@ -0,0 +1,28 @@
+use std::io::{BufRead, Write};
+use std::ops::Range;
+
+use crate::simulator::memory::Memory;
+
+pub mod console;
+pub mod display;
+pub mod timer;
+
+pub trait Device {
+    fn get_range(&self) -> Range<u32>;
+
+    fn load(&mut self, address: u32, input: &mut dyn BufRead) -> u32;
+
+    fn store(&mut self, address: u32, value: u32, output: &mut dyn Write);
+
+    fn tick(&mut self, _input: &mut dyn BufRead) {}
+
+    fn get_interrupts(&self) -> u32 {
+        0
+    }
+
+    fn get_memory_range(&self) -> Range<u32> {
+        0..0
+    }
+
+    fn finish(&mut self, _memory: &Memory) {}
+}
@@ -0,0 +1,105 @@
+use std::io::{BufRead, Write};
+use std::ops::Range;
+
+use crate::simulator::device::Device;
+
+pub const CONSOLE_ADDRESS: u32 = 0xffff0000;
+
+const RECEIVER_CONTROL: u32 = 0x0;
+const RECEIVER_DATA: u32 = 0x4;
+const TRANSMITTER_CONTROL: u32 = 0x8;
+const TRANSMITTER_DATA: u32 = 0xc;
+
+const READY: u32 = 0x1;
+const INTERRUPT_ENABLE: u32 = 0x2;
+
+pub const RECEIVER_INTERRUPT: u32 = 1 << 8;
+pub const TRANSMITTER_INTERRUPT: u32 = 1 << 9;
+
+pub struct Console {
+    receiver_control: u32,
+    receiver_data: u32,
+    transmitter_control: u32,
+}
+
+impl Default for Console {
+    fn default() -> Self {
+        Self {
+            receiver_control: 0,
+            receiver_data: 0,
+            transmitter_control: READY,
+        }
+    }
+}
+
+impl Console {
+    fn receive(&mut self, input: &mut dyn BufRead) {
+        if self.receiver_control & READY != 0 {
+            return;
+        }
+        let byte = input
+            .fill_buf()
+            .ok()
+            .and_then(|buffer| buffer.first().copied());
+        if let Some(byte) = byte {
+            input.consume(1);
+            self.receiver_data = byte as u32;
+            self.receiver_control |= READY;
+        }
+    }
+}
+
+impl Device for Console {
+    fn get_range(&self) -> Range<u32> {
+        CONSOLE_ADDRESS..CONSOLE_ADDRESS + TRANSMITTER_DATA + 4
+    }
+
+    fn load(&mut self, address: u32, input: &mut dyn BufRead) -> u32 {
+        match address - CONSOLE_ADDRESS {
+            RECEIVER_CONTROL => {
+                self.receive(input);
+                self.receiver_control
+            }
+            RECEIVER_DATA => {
+                self.receiver_control &= !READY;
+                self.receiver_data
+            }
+            TRANSMITTER_CONTROL => self.transmitter_control,
+            _ => 0,
+        }
+    }
+
+    fn store(&mut self, address: u32, value: u32, output: &mut dyn Write) {
+        match address - CONSOLE_ADDRESS {
+            RECEIVER_CONTROL => {
+                self.receiver_control = (self.receiver_control & READY) | (value & INTERRUPT_ENABLE)
+            }
+            TRANSMITTER_CONTROL => {
+                self.transmitter_control =
+                    (self.transmitter_control & READY) | (value & INTERRUPT_ENABLE)
+            }
+            TRANSMITTER_DATA => output
+                .write_all(&[value as u8])
+                .expect("Failed to write output."),
+            _ => {}
+        }
+    }
+
+    fn tick(&mut self, input: &mut dyn BufRead) {
+        if self.receiver_control & INTERRUPT_ENABLE != 0 {
+            self.receive(input);
+        }
+    }
+
+    fn get_interrupts(&self) -> u32 {
+        let is_raised = |control: u32| control == READY | INTERRUPT_ENABLE;
+        let mut interrupts = 0;
+        if is_raised(self.receiver_control) {
+            interrupts |= RECEIVER_INTERRUPT;
+        }
+        if is_raised(self.transmitter_control) {
+            interrupts |= TRANSMITTER_INTERRUPT;
+        }
+        interrupts
+    }
+}
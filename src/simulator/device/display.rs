@@ -0,0 +1,147 @@
+use std::fs;
+use std::io::{BufRead, Write};
+use std::ops::Range;
+use std::path::PathBuf;
+
+use crate::constants::WORD;
+use crate::simulator::device::Device;
+use crate::simulator::memory::Memory;
+
+pub const DISPLAY_ADDRESS: u32 = 0x10040000;
+pub const DISPLAY_WIDTH: u32 = 64;
+pub const DISPLAY_HEIGHT: u32 = 32;
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const STORED_BLOCK_SIZE: usize = 0xffff;
+
+pub struct Display {
+    width: u32,
+    height: u32,
+    base_address: u32,
+    filepath: PathBuf,
+}
+
+fn compute_crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(0xffff_ffff, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| {
+            if crc & 1 == 0 {
+                crc >> 1
+            } else {
+                (crc >> 1) ^ 0xedb8_8320
+            }
+        })
+    })
+}
+
+fn compute_adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = compute_crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+impl Display {
+    pub fn new(width: u32, height: u32, base_address: u32, filepath: PathBuf) -> Self {
+        if !matches!(
+            filepath
+                .extension()
+                .and_then(|extension| extension.to_str()),
+            Some("png") | Some("ppm")
+        ) {
+            panic!("Display file must end in .png or .ppm.");
+        }
+        Self {
+            width,
+            height,
+            base_address,
+            filepath,
+        }
+    }
+
+    fn render_rows(&self, memory: &Memory) -> Vec<Vec<u8>> {
+        (0..self.height)
+            .map(|y| {
+                (0..self.width)
+                    .flat_map(|x| {
+                        let address = self.base_address + (y * self.width + x) * WORD as u32;
+                        let [_, red, green, blue] = memory.read_word(address).to_be_bytes();
+                        [red, green, blue]
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn encode_ppm(&self, rows: &[Vec<u8>]) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        rows.iter().for_each(|row| ppm.extend(row));
+        ppm
+    }
+
+    fn encode_png(&self, rows: &[Vec<u8>]) -> Vec<u8> {
+        let mut header = vec![];
+        header.extend(self.width.to_be_bytes());
+        header.extend(self.height.to_be_bytes());
+        header.extend([8, 2, 0, 0, 0]);
+
+        let raw = rows
+            .iter()
+            .flat_map(|row| std::iter::once(0).chain(row.iter().copied()))
+            .collect::<Vec<u8>>();
+        let mut data = vec![0x78, 0x01];
+        let blocks = raw.chunks(STORED_BLOCK_SIZE).collect::<Vec<&[u8]>>();
+        blocks.iter().enumerate().for_each(|(index, block)| {
+            data.push((index + 1 == blocks.len()) as u8);
+            data.extend((block.len() as u16).to_le_bytes());
+            data.extend((!(block.len() as u16)).to_le_bytes());
+            data.extend(*block);
+        });
+        data.extend(compute_adler32(&raw).to_be_bytes());
+
+        let mut png = PNG_SIGNATURE.to_vec();
+        push_chunk(&mut png, b"IHDR", &header);
+        push_chunk(&mut png, b"IDAT", &data);
+        push_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+impl Device for Display {
+    fn get_range(&self) -> Range<u32> {
+        0..0
+    }
+
+    fn load(&mut self, _address: u32, _input: &mut dyn BufRead) -> u32 {
+        0
+    }
+
+    fn store(&mut self, _address: u32, _value: u32, _output: &mut dyn Write) {}
+
+    fn get_memory_range(&self) -> Range<u32> {
+        self.base_address..self.base_address + self.width * self.height * WORD as u32
+    }
+
+    fn finish(&mut self, memory: &Memory) {
+        let rows = self.render_rows(memory);
+        let image = if self
+            .filepath
+            .extension()
+            .is_some_and(|extension| extension == "png")
+        {
+            self.encode_png(&rows)
+        } else {
+            self.encode_ppm(&rows)
+        };
+        fs::write(&self.filepath, image).expect("Failed to write display file.");
+    }
+}
@@ -0,0 +1,62 @@
+use std::io::{BufRead, Write};
+use std::ops::Range;
+
+use crate::simulator::device::Device;
+
+pub const TIMER_ADDRESS: u32 = 0xffff0010;
+
+const COUNTER: u32 = 0x0;
+const COMPARE: u32 = 0x4;
+const CONTROL: u32 = 0x8;
+
+const EXPIRED: u32 = 0x1;
+const INTERRUPT_ENABLE: u32 = 0x2;
+
+pub const TIMER_INTERRUPT: u32 = 1 << 15;
+
+#[derive(Default)]
+pub struct Timer {
+    counter: u32,
+    compare: u32,
+    control: u32,
+}
+
+impl Device for Timer {
+    fn get_range(&self) -> Range<u32> {
+        TIMER_ADDRESS..TIMER_ADDRESS + CONTROL + 4
+    }
+
+    fn load(&mut self, address: u32, _input: &mut dyn BufRead) -> u32 {
+        match address - TIMER_ADDRESS {
+            COUNTER => self.counter,
+            COMPARE => self.compare,
+            CONTROL => self.control,
+            _ => 0,
+        }
+    }
+
+    fn store(&mut self, address: u32, value: u32, _output: &mut dyn Write) {
+        match address - TIMER_ADDRESS {
+            COUNTER => self.counter = value,
+            COMPARE => self.compare = value,
+            CONTROL => self.control = value & (EXPIRED | INTERRUPT_ENABLE),
+            _ => {}
+        }
+    }
+
+    fn tick(&mut self, _input: &mut dyn BufRead) {
+        self.counter = self.counter.wrapping_add(1);
+        if self.compare != 0 && self.counter >= self.compare {
+            self.counter = 0;
+            self.control |= EXPIRED;
+        }
+    }
+
+    fn get_interrupts(&self) -> u32 {
+        if self.control == EXPIRED | INTERRUPT_ENABLE {
+            TIMER_INTERRUPT
+        } else {
+            0
+        }
+    }
+}
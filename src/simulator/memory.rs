@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use crate::endianness::Endianness;
+
+const PAGE_SIZE: u32 = 0x1000;
+
+pub struct Memory {
+    pages: HashMap<u32, Vec<u8>>,
+    pub endianness: Endianness,
+    journal: Option<Vec<(u32, u8)>>,
+}
+
+impl Memory {
+    pub fn new(endianness: Endianness) -> Self {
+        Self {
+            pages: HashMap::new(),
+            endianness,
+            journal: None,
+        }
+    }
+
+    pub fn read_byte(&self, address: u32) -> u8 {
+        self.pages
+            .get(&(address / PAGE_SIZE))
+            .map_or(0, |page| page[(address % PAGE_SIZE) as usize])
+    }
+
+    pub fn start_journal(&mut self) {
+        self.journal = Some(vec![]);
+    }
+
+    pub fn take_journal(&mut self) -> Vec<(u32, u8)> {
+        self.journal.take().unwrap_or_default()
+    }
+
+    pub fn write_byte(&mut self, address: u32, value: u8) {
+        let previous = self.read_byte(address);
+        if let Some(journal) = &mut self.journal {
+            journal.push((address, previous));
+        }
+        let page = self
+            .pages
+            .entry(address / PAGE_SIZE)
+            .or_insert_with(|| vec![0; PAGE_SIZE as usize]);
+        page[(address % PAGE_SIZE) as usize] = value;
+    }
+
+    pub fn read_bytes(&self, address: u32, size: u32) -> Vec<u8> {
+        (0..size)
+            .map(|offset| self.read_byte(address.wrapping_add(offset)))
+            .collect()
+    }
+
+    pub fn write_bytes(&mut self, address: u32, bytes: &[u8]) {
+        bytes
+            .iter()
+            .enumerate()
+            .for_each(|(offset, byte)| self.write_byte(address.wrapping_add(offset as u32), *byte));
+    }
+
+    pub fn read_half(&self, address: u32) -> u16 {
+        self.endianness.bytes_to_half(&self.read_bytes(address, 2))
+    }
+
+    pub fn write_half(&mut self, address: u32, value: u16) {
+        let bytes = self.endianness.half_to_bytes(value);
+        self.write_bytes(address, &bytes);
+    }
+
+    pub fn read_word(&self, address: u32) -> u32 {
+        self.endianness.bytes_to_word(&self.read_bytes(address, 4))
+    }
+
+    pub fn write_word(&mut self, address: u32, value: u32) {
+        let bytes = self.endianness.word_to_bytes(value);
+        self.write_bytes(address, &bytes);
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::io::{self, BufWriter, Write};
+
+use crate::constants::WORD;
+use crate::disassembler::{render_instruction, Labels};
+use crate::hazard::get_sources;
+use crate::register::get_register_label;
+use crate::simulator::{Simulator, StopReason};
+use crate::text::Text;
+use crate::Program;
+
+const STAGE_NAMES: [&str; 5] = ["IF", "ID", "EX", "MEM", "WB"];
+const STAGE_WIDTH: usize = 22;
+
+const FETCH: usize = 0;
+const DECODE: usize = 1;
+const EXECUTE: usize = 2;
+const MEMORY: usize = 3;
+const WRITE_BACK: usize = 4;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum BranchStrategy {
+    STALL,
+    PREDICTNOTTAKEN,
+    DELAYED,
+}
+
+impl BranchStrategy {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "stall" => Some(BranchStrategy::STALL),
+            "predict-not-taken" => Some(BranchStrategy::PREDICTNOTTAKEN),
+            "delayed" => Some(BranchStrategy::DELAYED),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct PipelineOptions {
+    pub forwarding: bool,
+    pub branch_strategy: BranchStrategy,
+}
+
+struct Record {
+    text: String,
+    stages: [u64; 5],
+    stall: Option<String>,
+    forwards: Vec<(u64, String)>,
+}
+
+struct Flush {
+    text: String,
+    start: u64,
+    end: u64,
+}
+
+struct Producer {
+    stages: [u64; 5],
+    is_load: bool,
+}
+
+pub struct Pipeline {
+    options: PipelineOptions,
+    labels: Labels,
+    records: VecDeque<Record>,
+    flushes: VecDeque<Flush>,
+    producers: BTreeMap<i32, Producer>,
+    next_fetch: u64,
+    next_row: u64,
+    diagram: Box<dyn Write>,
+    instructions: u64,
+    cycles: u64,
+    pub data_stalls: u64,
+    pub load_use_stalls: u64,
+    pub control_stalls: u64,
+}
+
+impl Pipeline {
+    pub fn new(program: &Program, options: PipelineOptions) -> Self {
+        let mut labels = Labels::new();
+        program.labels.iter().for_each(|label| {
+            labels
+                .entry(label.address as u32)
+                .or_default()
+                .push(label.name.clone())
+        });
+
+        Self {
+            options,
+            labels,
+            records: VecDeque::new(),
+            flushes: VecDeque::new(),
+            producers: BTreeMap::new(),
+            next_fetch: 1,
+            next_row: 1,
+            diagram: Box::new(BufWriter::new(io::stderr())),
+            instructions: 0,
+            cycles: 0,
+            data_stalls: 0,
+            load_use_stalls: 0,
+            control_stalls: 0,
+        }
+    }
+
+    fn render(&self, word: u32, address: u32) -> String {
+        render_instruction(&Text::from_word(word), address, &self.labels)
+            .unwrap_or_else(|| format!(".word 0x{:08x}", word))
+            .replace('\t', " ")
+    }
+
+    fn record(&mut self, address: u32, word: u32, next_address: u32, next_word: u32) {
+        let text = Text::from_word(word);
+        let instruction = match text.get_instruction() {
+            Some(instruction) => instruction,
+            None => return,
+        };
+        let sources = get_sources(&text);
+        let (_, destinations) = text.get_register_usage();
+        let is_control = instruction.is_control();
+        let is_load = instruction.is_load();
+
+        let sequential_fetch = self.records.back().map_or(1, |previous| {
+            (previous.stages[FETCH] + 1).max(previous.stages[DECODE])
+        });
+        let fetch = sequential_fetch.max(self.next_fetch);
+        self.control_stalls += fetch - sequential_fetch;
+
+        let (decode, structural_execute) =
+            self.records
+                .back()
+                .map_or((fetch + 1, fetch + 2), |previous| {
+                    let decode = (fetch + 1).max(previous.stages[EXECUTE]);
+                    (decode, (decode + 1).max(previous.stages[MEMORY]))
+                });
+
+        let mut execute = structural_execute;
+        let mut stall: Option<(i32, bool)> = None;
+        sources.iter().for_each(|register| {
+            if let Some(producer) = self.producers.get(register) {
+                let ready = match (self.options.forwarding, is_control, producer.is_load) {
+                    (true, true, false) => producer.stages[MEMORY] + 1,
+                    (true, true, true) => producer.stages[WRITE_BACK] + 1,
+                    (true, false, false) => producer.stages[MEMORY],
+                    (true, false, true) => producer.stages[WRITE_BACK],
+                    (false, _, _) => producer.stages[WRITE_BACK] + 1,
+                };
+                if ready > execute {
+                    execute = ready;
+                    stall = Some((*register, producer.is_load));
+                }
+            }
+        });
+
+        let stall = stall.map(|(register, is_load_producer)| {
+            let count = execute - structural_execute;
+            if is_load_producer && self.options.forwarding && !is_control {
+                self.load_use_stalls += count;
+                format!("load-use stall on {}", get_register_label(register))
+            } else {
+                self.data_stalls += count;
+                format!("data stall on {}", get_register_label(register))
+            }
+        });
+
+        let mut forwards: Vec<(u64, String)> = vec![];
+        if self.options.forwarding {
+            let (cycle, stage) = if is_control {
+                (execute - 1, "ID")
+            } else {
+                (execute, "EX")
+            };
+            sources.iter().for_each(|register| {
+                if let Some(producer) = self.producers.get(register) {
+                    let path = if cycle == producer.stages[MEMORY] {
+                        Some("EX/MEM")
+                    } else if cycle == producer.stages[WRITE_BACK] {
+                        Some("MEM/WB")
+                    } else {
+                        None
+                    };
+                    if let Some(path) = path {
+                        forwards.push((
+                            cycle,
+                            format!(
+                                "forward {} {} -> {}",
+                                get_register_label(*register),
+                                path,
+                                stage
+                            ),
+                        ));
+                    }
+                }
+            });
+        }
+
+        if is_control {
+            let is_taken = next_address != address.wrapping_add(WORD as u32);
+            match self.options.branch_strategy {
+                BranchStrategy::STALL => self.next_fetch = execute,
+                BranchStrategy::PREDICTNOTTAKEN if is_taken => {
+                    self.next_fetch = execute;
+                    self.flushes.push_back(Flush {
+                        text: self.render(next_word, address.wrapping_add(WORD as u32)),
+                        start: decode,
+                        end: execute,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        let stages = [fetch, decode, execute, execute + 1, execute + 2];
+        destinations.iter().for_each(|register| {
+            self.producers
+                .insert(*register, Producer { stages, is_load });
+        });
+        self.records.push_back(Record {
+            text: self.render(word, address),
+            stages,
+            stall,
+            forwards,
+        });
+        self.instructions += 1;
+        self.cycles = stages[WRITE_BACK];
+
+        // Instructions recorded later are fetched after this one, so earlier rows are final.
+        self.write_rows(fetch);
+    }
+
+    pub fn run(&mut self, simulator: &mut Simulator, max_steps: Option<u64>) -> StopReason {
+        if self.options.branch_strategy == BranchStrategy::DELAYED && !simulator.delay_slots {
+            panic!("The delayed branch strategy requires --delay-slots.");
+        }
+        self.write_header();
+        let stop_reason = loop {
+            if let Some(stop_reason) = simulator.check_stop(max_steps) {
+                break stop_reason;
+            }
+            let address = simulator.pc;
+            let word = simulator.memory.read_word(address);
+            let next_word = simulator
+                .memory
+                .read_word(address.wrapping_add(WORD as u32));
+            if let Err(exception) = simulator.step() {
+                break StopReason::EXCEPTION(exception);
+            }
+            self.record(address, word, simulator.pc, next_word);
+        };
+
+        self.write_rows(self.cycles);
+        self.diagram
+            .flush()
+            .expect("Failed to write pipeline diagram.");
+        stop_reason
+    }
+
+    fn write_header(&mut self) {
+        let mut header = format!("{:>5}", "Cycle");
+        STAGE_NAMES.iter().for_each(|name| {
+            header.push_str(&format!("  {:<width$}", name, width = STAGE_WIDTH));
+        });
+        writeln!(self.diagram, "{}  Notes", header).expect("Failed to write pipeline diagram.");
+    }
+
+    fn write_rows(&mut self, last_cycle: u64) {
+        (self.next_row..=last_cycle).for_each(|cycle| {
+            let row = self.format_row(cycle);
+            writeln!(self.diagram, "{}", row).expect("Failed to write pipeline diagram.");
+        });
+        self.next_row = self.next_row.max(last_cycle + 1);
+
+        let next_row = self.next_row;
+        while self.records.len() > 1
+            && self
+                .records
+                .front()
+                .is_some_and(|record| record.stages[WRITE_BACK] < next_row)
+        {
+            self.records.pop_front();
+        }
+        while self
+            .flushes
+            .front()
+            .is_some_and(|flush| flush.end <= next_row)
+        {
+            self.flushes.pop_front();
+        }
+    }
+
+    fn format_row(&self, cycle: u64) -> String {
+        let mut stages = vec![String::new(); 5];
+        let mut notes: Vec<String> = vec![];
+        self.records.iter().for_each(|record| {
+            let stage = (0..5).find(|&stage| {
+                let end = record
+                    .stages
+                    .get(stage + 1)
+                    .copied()
+                    .unwrap_or(record.stages[WRITE_BACK] + 1);
+                (record.stages[stage]..end).contains(&cycle)
+            });
+            if let Some(stage) = stage {
+                stages[stage] = record.text.clone();
+            }
+            if stage == Some(DECODE) && cycle + 1 < record.stages[EXECUTE] {
+                notes.extend(record.stall.clone());
+            }
+            record
+                .forwards
+                .iter()
+                .filter(|(forward_cycle, _)| *forward_cycle == cycle)
+                .for_each(|(_, forward)| notes.push(forward.clone()));
+        });
+        self.flushes
+            .iter()
+            .filter(|flush| (flush.start..flush.end).contains(&cycle))
+            .for_each(|flush| {
+                stages[FETCH] = flush.text.clone();
+                if cycle + 1 == flush.end {
+                    notes.push(format!("flush {}", flush.text));
+                }
+            });
+
+        let mut row = format!("{:>5}", cycle);
+        stages.iter().for_each(|stage| {
+            row.push_str(&format!("  {:<width$}", stage, width = STAGE_WIDTH));
+        });
+        row.push_str(&format!("  {}", notes.join(", ")));
+        row.trim_end().to_string()
+    }
+
+    pub fn format_summary(&self) -> String {
+        let cpi = if self.instructions == 0 {
+            0.0
+        } else {
+            self.cycles as f64 / self.instructions as f64
+        };
+        format!(
+            "Instructions: {}\nCycles: {}\nCPI: {:.2}\nStalls: {} data, {} load-use, {} control\n",
+            self.instructions,
+            self.cycles,
+            cpi,
+            self.data_stalls,
+            self.load_use_stalls,
+            self.control_stalls
+        )
+    }
+}
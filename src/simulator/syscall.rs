@@ -0,0 +1,222 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use crate::simulator::{Exception, Simulator};
+
+const RESULT_REGISTER: usize = 2;
+const ARGUMENT_REGISTERS: [usize; 3] = [4, 5, 6];
+
+const STDIN_DESCRIPTOR: u32 = 0;
+const STDOUT_DESCRIPTOR: u32 = 1;
+const STDERR_DESCRIPTOR: u32 = 2;
+
+const WORD_MASK: u32 = 3;
+
+const MAX_TRANSFER_SIZE: u32 = 0x10_0000;
+
+const OPEN_READ: u32 = 0;
+const OPEN_WRITE: u32 = 1;
+const OPEN_APPEND: u32 = 9;
+
+pub fn resolve_sandbox_path(sandbox: &Option<PathBuf>, name: &str) -> Option<PathBuf> {
+    let sandbox = match sandbox {
+        Some(sandbox) => sandbox,
+        None => return Some(PathBuf::from(name)),
+    };
+    let path = Path::new(name);
+    if path
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        return None;
+    }
+
+    let root = sandbox.canonicalize().ok()?;
+    let path = root.join(path);
+    let parent = path.parent()?.canonicalize().ok()?;
+    let path = path.canonicalize().unwrap_or(path);
+    (parent.starts_with(&root) && path.starts_with(&root)).then_some(path)
+}
+
+impl Simulator {
+    fn get_argument(&self, index: usize) -> u32 {
+        self.registers[ARGUMENT_REGISTERS[index]]
+    }
+
+    fn set_result(&mut self, value: u32) {
+        self.registers[RESULT_REGISTER] = value;
+    }
+
+    fn read_string(&self, address: u32) -> String {
+        let mut bytes: Vec<u8> = vec![];
+        let mut address = address;
+        loop {
+            let byte = self.memory.read_byte(address);
+            if byte == 0 {
+                break;
+            }
+            bytes.push(byte);
+            address = address.wrapping_add(1);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+
+    fn read_line(&mut self) -> String {
+        self.output.flush().expect("Failed to write output.");
+        let mut line = String::new();
+        self.input
+            .read_line(&mut line)
+            .expect("Failed to read input.");
+        line
+    }
+
+    fn read_input(&mut self, size: usize) -> Vec<u8> {
+        self.output.flush().expect("Failed to write output.");
+        let mut bytes = vec![0; size];
+        let count = self.input.read(&mut bytes).expect("Failed to read input.");
+        bytes.truncate(count);
+        bytes
+    }
+
+    fn write_output(&mut self, bytes: &[u8]) {
+        self.output
+            .write_all(bytes)
+            .expect("Failed to write output.");
+    }
+
+    fn open_file(&mut self) -> u32 {
+        let name = self.read_string(self.get_argument(0));
+        let mut options = OpenOptions::new();
+        match self.get_argument(1) {
+            OPEN_READ => options.read(true),
+            OPEN_WRITE => options.write(true).create(true).truncate(true),
+            OPEN_APPEND => options.append(true).create(true),
+            _ => return -1i32 as u32,
+        };
+
+        let file =
+            resolve_sandbox_path(&self.sandbox, &name).and_then(|path| options.open(path).ok());
+        match file {
+            Some(file) => {
+                let descriptor = self.next_descriptor;
+                self.files.insert(descriptor, file);
+                self.next_descriptor += 1;
+                descriptor
+            }
+            None => -1i32 as u32,
+        }
+    }
+
+    fn read_file(&mut self) -> u32 {
+        let (descriptor, address, size) = (
+            self.get_argument(0),
+            self.get_argument(1),
+            self.get_argument(2),
+        );
+        if size > MAX_TRANSFER_SIZE
+            || (descriptor != STDIN_DESCRIPTOR && !self.files.contains_key(&descriptor))
+        {
+            return -1i32 as u32;
+        }
+
+        let bytes = match descriptor {
+            STDIN_DESCRIPTOR => Some(self.read_input(size as usize)),
+            _ => self.files.get_mut(&descriptor).and_then(|file: &mut File| {
+                let mut bytes = vec![0; size as usize];
+                let count = file.read(&mut bytes).ok()?;
+                bytes.truncate(count);
+                Some(bytes)
+            }),
+        };
+        match bytes {
+            Some(bytes) => {
+                self.memory.write_bytes(address, &bytes);
+                bytes.len() as u32
+            }
+            None => -1i32 as u32,
+        }
+    }
+
+    fn write_file(&mut self) -> u32 {
+        let (descriptor, address, size) = (
+            self.get_argument(0),
+            self.get_argument(1),
+            self.get_argument(2),
+        );
+        if size > MAX_TRANSFER_SIZE
+            || (!matches!(descriptor, STDOUT_DESCRIPTOR | STDERR_DESCRIPTOR)
+                && !self.files.contains_key(&descriptor))
+        {
+            return -1i32 as u32;
+        }
+
+        let bytes = self.memory.read_bytes(address, size);
+        let is_written = match descriptor {
+            STDOUT_DESCRIPTOR => {
+                self.write_output(&bytes);
+                true
+            }
+            STDERR_DESCRIPTOR => io::stderr().write_all(&bytes).is_ok(),
+            _ => self
+                .files
+                .get_mut(&descriptor)
+                .is_some_and(|file| file.write_all(&bytes).is_ok()),
+        };
+        if is_written {
+            size
+        } else {
+            -1i32 as u32
+        }
+    }
+
+    fn close_file(&mut self) {
+        let descriptor = self.get_argument(0);
+        self.files.remove(&descriptor);
+    }
+
+    pub(crate) fn syscall(&mut self) -> Result<(), Exception> {
+        match self.registers[RESULT_REGISTER] {
+            1 => {
+                let text = (self.get_argument(0) as i32).to_string();
+                self.write_output(text.as_bytes());
+            }
+            4 => {
+                let text = self.read_string(self.get_argument(0));
+                self.write_output(text.as_bytes());
+            }
+            5 => {
+                let value = self.read_line().trim().parse::<i32>().unwrap_or(0);
+                self.set_result(value as u32);
+            }
+            9 => {
+                let address = self.heap_address;
+                let size = (self.get_argument(0) + WORD_MASK) & !WORD_MASK;
+                self.heap_address = self.heap_address.wrapping_add(size);
+                self.set_result(address);
+            }
+            10 => self.exit_code = Some(0),
+            11 => self.write_output(&[self.get_argument(0) as u8]),
+            12 => {
+                let value = self.read_input(1).first().map_or(0, |&byte| byte as u32);
+                self.set_result(value);
+            }
+            13 => {
+                let descriptor = self.open_file();
+                self.set_result(descriptor);
+            }
+            14 => {
+                let count = self.read_file();
+                self.set_result(count);
+            }
+            15 => {
+                let count = self.write_file();
+                self.set_result(count);
+            }
+            16 => self.close_file(),
+            17 => self.exit_code = Some(self.get_argument(0) as i32),
+            _ => return Err(Exception::SYS),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+const NOP_CODE: &str = "sll\t$0, $0, 0";
+
+#[derive(Clone)]
+pub struct Source {
+    pub line_number: usize,
+    pub line: String,
+    pub code: String,
+    pub pseudo_instruction: Option<String>,
+}
+
+impl Source {
+    pub fn new(line_number: usize, line: &str, code: &str) -> Self {
+        Self {
+            line_number,
+            line: line.to_string(),
+            code: code.to_string(),
+            pseudo_instruction: None,
+        }
+    }
+
+    pub fn new_nop(line_number: usize) -> Self {
+        Self {
+            pseudo_instruction: Some(String::from("nop")),
+            ..Self::new(line_number, "nop", NOP_CODE)
+        }
+    }
+
+    pub fn get_mnemonic(&self) -> &str {
+        self.code
+            .trim_start()
+            .split('\t')
+            .next()
+            .unwrap_or_default()
+    }
+}
+
+pub fn format_source(code: &str) -> String {
+    code.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
@@ -0,0 +1,111 @@
+use crate::constants::{TEXT_SECTION_MIN_ADDRESS, WORD};
+use crate::section::Section;
+use crate::Program;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Binding {
+    LOCAL,
+    GLOBAL,
+}
+
+impl Binding {
+    pub fn to_name(self) -> &'static str {
+        match self {
+            Binding::LOCAL => "local",
+            Binding::GLOBAL => "global",
+        }
+    }
+}
+
+pub struct Symbol {
+    pub name: String,
+    pub address: i32,
+    pub size: i32,
+    pub section: Section,
+    pub binding: Binding,
+    pub line_number: Option<usize>,
+}
+
+pub fn get_text_end_address(program: &Program) -> i32 {
+    TEXT_SECTION_MIN_ADDRESS + program.texts.len() as i32 * WORD
+}
+
+pub fn get_data_end_address(program: &Program) -> Option<i32> {
+    program.data.last().map(|datum| datum.address + WORD)
+}
+
+pub fn collect_symbols(program: &Program) -> Vec<Symbol> {
+    let binding = |name: &str| {
+        if program.is_global(name) {
+            Binding::GLOBAL
+        } else {
+            Binding::LOCAL
+        }
+    };
+
+    let mut symbols = program
+        .labels
+        .iter()
+        .map(|label| Symbol {
+            name: label.name.clone(),
+            address: label.address,
+            size: 0,
+            section: Section::TEXT,
+            binding: binding(&label.name),
+            line_number: label.source.as_ref().map(|source| source.line_number),
+        })
+        .chain(
+            program
+                .data
+                .iter()
+                .filter(|datum| datum.is_named)
+                .map(|datum| Symbol {
+                    name: datum.name.clone(),
+                    address: datum.address,
+                    size: 0,
+                    section: Section::DATA,
+                    binding: binding(&datum.name),
+                    line_number: datum.source.as_ref().map(|source| source.line_number),
+                }),
+        )
+        .collect::<Vec<Symbol>>();
+    symbols.sort_by_key(|symbol| symbol.address as u32);
+
+    let sizes = symbols
+        .iter()
+        .map(|symbol| {
+            let end_address = symbols
+                .iter()
+                .filter(|next| next.section == symbol.section && next.address > symbol.address)
+                .map(|next| next.address)
+                .next()
+                .unwrap_or_else(|| match symbol.section {
+                    Section::DATA => get_data_end_address(program).unwrap_or(symbol.address),
+                    _ => get_text_end_address(program),
+                });
+            end_address - symbol.address
+        })
+        .collect::<Vec<i32>>();
+    symbols
+        .iter_mut()
+        .zip(sizes)
+        .for_each(|(symbol, size)| symbol.size = size);
+
+    symbols.extend(program.get_externs().iter().map(|name| Symbol {
+        name: name.clone(),
+        address: 0,
+        size: 0,
+        section: Section::NONE,
+        binding: Binding::GLOBAL,
+        line_number: None,
+    }));
+    symbols
+}
+
+pub fn find_symbol_by_address(symbols: &[Symbol], address: i32) -> Option<&Symbol> {
+    symbols.iter().rev().find(|symbol| {
+        symbol.section != Section::NONE
+            && symbol.address <= address
+            && address < symbol.address + symbol.size.max(WORD)
+    })
+}
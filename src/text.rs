@@ -1,9 +1,18 @@
 use regex::Regex;
+use std::sync::OnceLock;
 
 use crate::constants::INSTRUCTION_TABLE;
 use crate::datum::{find_datum, Datum};
-use crate::instruction::{convert_opcode_to_format, Instruction, InstructionFormat};
+use crate::endianness::Endianness;
+use crate::instruction::{
+    convert_opcode_to_format, find_instruction_by_encoding, Instruction, InstructionFormat,
+};
 use crate::label::{find_label, Label};
+use crate::register::{
+    resolve_register, CONDITION_FLAG_REGISTER, FLOAT_REGISTER_BASE, HI_REGISTER, LO_REGISTER,
+};
+use crate::relocation::{Relocation, RelocationType};
+use crate::source::Source;
 use crate::utils::{convert_int_to_binary, convert_string_to_int, get_address_difference};
 
 #[derive(Clone)]
@@ -12,20 +21,33 @@ enum ArgumentType {
     REGISTER,
     LABEL,
     STACK,
+    RELOCATION,
 }
 
 pub struct Text {
-    rs: i32,
-    rt: i32,
-    rd: i32,
-    shamt: i32,
-    funct: i32,
-    opcode: i32,
-    immediate: i32,
-    address: i32,
+    pub rs: i32,
+    pub rt: i32,
+    pub rd: i32,
+    pub shamt: i32,
+    pub funct: i32,
+    pub opcode: i32,
+    pub immediate: i32,
+    pub address: i32,
+    pub relocation: Option<Relocation>,
+    pub source: Option<Source>,
+}
+
+fn get_float_registers(register: i32, is_double: bool) -> Vec<i32> {
+    let register = FLOAT_REGISTER_BASE + register;
+    if is_double {
+        vec![register, register + 1]
+    } else {
+        vec![register]
+    }
 }
 
 impl Text {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         rs: i32,
         rt: i32,
@@ -45,11 +67,112 @@ impl Text {
             opcode,
             immediate,
             address,
+            relocation: None,
+            source: None,
+        }
+    }
+
+    pub fn from_word(word: u32) -> Self {
+        let word = word as i32;
+        let opcode = (word >> 26) & 0x3f;
+        let rs = (word >> 21) & 0x1f;
+        let rt = (word >> 16) & 0x1f;
+        match convert_opcode_to_format(opcode, rs) {
+            InstructionFormat::REGISTER => Text::new(
+                rs,
+                rt,
+                (word >> 11) & 0x1f,
+                (word >> 6) & 0x1f,
+                word & 0x3f,
+                opcode,
+                0,
+                0,
+            ),
+            InstructionFormat::JUMP => Text::new(0, 0, 0, 0, -1, opcode, 0, word & 0x3ffffff),
+            _ => Text::new(rs, rt, 0, 0, -1, opcode, word & 0xffff, 0),
         }
     }
 
+    pub fn with_relocation(mut self, relocation: Option<Relocation>) -> Self {
+        self.relocation = relocation;
+        self
+    }
+
+    pub fn with_source(mut self, source: &Source) -> Self {
+        self.source = Some(source.clone());
+        self
+    }
+
+    pub fn get_instruction(&self) -> Option<&'static Instruction> {
+        find_instruction_by_encoding(self)
+    }
+
+    pub fn get_register_usage(&self) -> (Vec<i32>, Vec<i32>) {
+        let instruction = self.get_instruction();
+        let name = instruction.map_or("", |instruction| instruction.name);
+        let is_double = instruction.is_some_and(|instruction| instruction.is_double());
+        let (sources, destinations) = match name {
+            "" | "break" | "j" => (vec![], vec![]),
+            "sll" | "srl" | "sra" => (vec![self.rt], vec![self.rd]),
+            "jr" => (vec![self.rs], vec![]),
+            "jalr" => (vec![self.rs], vec![self.rd]),
+            "mfhi" => (vec![HI_REGISTER], vec![self.rd]),
+            "mflo" => (vec![LO_REGISTER], vec![self.rd]),
+            "mthi" => (vec![self.rs], vec![HI_REGISTER]),
+            "mtlo" => (vec![self.rs], vec![LO_REGISTER]),
+            "mult" | "multu" | "div" | "divu" => {
+                (vec![self.rs, self.rt], vec![HI_REGISTER, LO_REGISTER])
+            }
+            "syscall" => (vec![2, 4, 5, 6], vec![2]),
+            "mfc0" => (vec![], vec![self.rt]),
+            "mtc0" => (vec![self.rt], vec![]),
+            "eret" => (vec![], vec![]),
+            "mfc1" => (get_float_registers(self.rd, false), vec![self.rt]),
+            "mtc1" => (vec![self.rt], get_float_registers(self.rd, false)),
+            "bc1f" | "bc1t" => (vec![CONDITION_FLAG_REGISTER], vec![]),
+            "lwc1" | "ldc1" => (vec![self.rs], get_float_registers(self.rt, is_double)),
+            "swc1" | "sdc1" => {
+                let mut sources = vec![self.rs];
+                sources.extend(get_float_registers(self.rt, is_double));
+                (sources, vec![])
+            }
+            _ if instruction.is_some_and(|instruction| instruction.is_float_compare()) => {
+                let mut sources = get_float_registers(self.rd, is_double);
+                sources.extend(get_float_registers(self.rt, is_double));
+                (sources, vec![CONDITION_FLAG_REGISTER])
+            }
+            _ if instruction.is_some_and(|instruction| instruction.is_float_operation()) => {
+                let mut sources = get_float_registers(self.rd, is_double);
+                if self.funct < 4 {
+                    sources.extend(get_float_registers(self.rt, is_double));
+                }
+                let is_double_result =
+                    instruction.is_some_and(|instruction| instruction.is_double_result());
+                (sources, get_float_registers(self.shamt, is_double_result))
+            }
+            "jal" => (vec![], vec![31]),
+            "lui" => (vec![], vec![self.rt]),
+            "beq" | "bne" | "sb" | "sh" | "sw" => (vec![self.rs, self.rt], vec![]),
+            "blez" | "bgtz" => (vec![self.rs], vec![]),
+            _ if convert_opcode_to_format(self.opcode, self.rs) == InstructionFormat::REGISTER => {
+                (vec![self.rs, self.rt], vec![self.rd])
+            }
+            _ => (vec![self.rs], vec![self.rt]),
+        };
+        (
+            sources
+                .into_iter()
+                .filter(|&register| register != 0)
+                .collect(),
+            destinations
+                .into_iter()
+                .filter(|&register| register != 0)
+                .collect(),
+        )
+    }
+
     pub fn to_binary(&self) -> String {
-        match convert_opcode_to_format(self.opcode) {
+        match convert_opcode_to_format(self.opcode, self.rs) {
             InstructionFormat::REGISTER => format!(
                 "{}{}{}{}{}{}",
                 convert_int_to_binary(self.opcode, 6),
@@ -74,6 +197,14 @@ impl Text {
             InstructionFormat::PSEUDO => panic!("A pseudo instruction found."),
         }
     }
+
+    pub fn to_word(&self) -> u32 {
+        u32::from_str_radix(&self.to_binary(), 2).unwrap()
+    }
+
+    pub fn to_bytes(&self, endianness: Endianness) -> Vec<u8> {
+        endianness.word_to_bytes(self.to_word()).to_vec()
+    }
 }
 
 pub fn get_text_from_code(
@@ -81,41 +212,89 @@ pub fn get_text_from_code(
     current_address: i32,
     data: &[Datum],
     labels: &[Label],
+    externs: &[String],
 ) -> Text {
-    if let [name, arguments] = text.trim_start().split('\t').collect::<Vec<&str>>()[..] {
+    if let [".word", value] = text.trim_start().split('\t').collect::<Vec<&str>>()[..] {
+        return Text::from_word(convert_string_to_int(value) as u32);
+    }
+
+    if let [name, ref arguments @ ..] = text.trim_start().split('\t').collect::<Vec<&str>>()[..] {
         let instruction = INSTRUCTION_TABLE.get(name).expect("Unknown instruction.");
 
-        let argument_texts = arguments
-            .split(',')
-            .map(|arg| arg.trim())
-            .collect::<Vec<&str>>();
+        let argument_texts = match arguments {
+            [] => vec![],
+            [arguments] => arguments.split(',').map(|arg| arg.trim()).collect(),
+            _ => panic!("Invalid instruction."),
+        };
 
-        let arguments = resolve_arguments(&argument_texts, &data, &labels);
+        let (arguments, relocation) =
+            resolve_arguments(&argument_texts, instruction, data, labels, externs);
 
-        get_text_by_format(&instruction, &arguments, current_address)
+        get_text_by_format(instruction, &arguments, current_address).with_relocation(relocation)
     } else {
         panic!("Invalid instruction.");
     }
 }
 
 fn get_text_by_format(instruction: &Instruction, arguments: &[i32], current_address: i32) -> Text {
-    let first_arg = *arguments.get(0).unwrap_or(&0);
+    let first_arg = *arguments.first().unwrap_or(&0);
     let second_arg = *arguments.get(1).unwrap_or(&0);
     let third_arg = *arguments.get(2).unwrap_or(&0);
 
-    match convert_opcode_to_format(instruction.opcode) {
+    match convert_opcode_to_format(instruction.opcode, instruction.format_code) {
         InstructionFormat::REGISTER => {
-            if instruction.is_shift() {
+            if instruction.is_float_compare() {
+                instruction.to_register_format_text(
+                    instruction.format_code,
+                    second_arg,
+                    first_arg,
+                    0,
+                )
+            } else if instruction.is_float_operation() {
+                instruction.to_register_format_text(
+                    instruction.format_code,
+                    third_arg,
+                    second_arg,
+                    first_arg,
+                )
+            } else if instruction.is_coprocessor() {
+                instruction.to_register_format_text(
+                    instruction.format_code,
+                    first_arg,
+                    second_arg,
+                    0,
+                )
+            } else if instruction.is_shift() {
                 instruction.to_register_format_text(0, second_arg, first_arg, third_arg)
-            } else if instruction.is_register_jump() {
+            } else if instruction.is_variable_shift() {
+                instruction.to_register_format_text(third_arg, second_arg, first_arg, 0)
+            } else if instruction.is_register_jump() && arguments.len() == 2 {
+                instruction.to_register_format_text(second_arg, 0, first_arg, 0)
+            } else if instruction.is_register_jump() && instruction.name == "jalr" {
+                instruction.to_register_format_text(first_arg, 0, 31, 0)
+            } else if instruction.is_register_jump() || instruction.is_move_to_hi_lo() {
                 instruction.to_register_format_text(first_arg, 0, 0, 0)
+            } else if instruction.is_move_from_hi_lo() {
+                instruction.to_register_format_text(0, 0, first_arg, 0)
+            } else if instruction.is_multiply_divide() {
+                instruction.to_register_format_text(first_arg, second_arg, 0, 0)
             } else {
                 instruction.to_register_format_text(second_arg, third_arg, first_arg, 0)
             }
         }
         InstructionFormat::JUMP => instruction.to_jump_format_text(first_arg >> 2),
         InstructionFormat::IMMEDIATE => {
-            if arguments.len() < 3 {
+            if instruction.is_coprocessor_branch() {
+                let difference = get_address_difference(current_address, first_arg);
+                instruction.to_immediate_format_text(
+                    instruction.format_code,
+                    instruction.funct,
+                    difference,
+                )
+            } else if instruction.is_branch_zero() {
+                let difference = get_address_difference(current_address, second_arg);
+                instruction.to_immediate_format_text(first_arg, 0, difference)
+            } else if arguments.len() < 3 {
                 instruction.to_immediate_format_text(0, first_arg, second_arg)
             } else if instruction.is_branch() {
                 let difference = get_address_difference(current_address, third_arg);
@@ -128,46 +307,107 @@ fn get_text_by_format(instruction: &Instruction, arguments: &[i32], current_addr
     }
 }
 
-fn resolve_arguments(argument_codes: &[&str], data: &[Datum], labels: &[Label]) -> Vec<i32> {
-    argument_codes
+fn resolve_arguments(
+    argument_codes: &[&str],
+    instruction: &Instruction,
+    data: &[Datum],
+    labels: &[Label],
+    externs: &[String],
+) -> (Vec<i32>, Option<Relocation>) {
+    let mut relocation = None;
+    let arguments = argument_codes
         .iter()
-        .flat_map(|argument_text| match resolve_argument_type(argument_text) {
-            ArgumentType::NUMBER => vec![convert_string_to_int(argument_text)],
-            ArgumentType::REGISTER => vec![convert_string_to_int(
-                &argument_text[1..argument_text.len()],
-            )],
-            ArgumentType::LABEL => {
-                if let Some(datum) = find_datum(argument_text, &data) {
-                    vec![datum.address]
-                } else if let Some(label) = find_label(argument_text, &labels) {
-                    vec![label.address]
-                } else {
-                    panic!("Failed to resolve argument value.");
+        .enumerate()
+        .flat_map(
+            |(index, argument_text)| match resolve_argument_type(argument_text) {
+                ArgumentType::NUMBER => vec![convert_string_to_int(argument_text)],
+                ArgumentType::REGISTER => vec![resolve_register(
+                    argument_text,
+                    instruction.is_float_operand(index),
+                )],
+                ArgumentType::LABEL => {
+                    let address = resolve_symbol_address(argument_text, data, labels, externs);
+                    relocation = resolve_label_relocation(instruction, argument_text, externs);
+                    vec![address]
                 }
-            }
-            ArgumentType::STACK => {
-                if let [offset, base] = argument_text.split('(').collect::<Vec<&str>>()[..] {
-                    let base = convert_string_to_int(&base[1..(base.len() - 1)]);
-                    let offset = convert_string_to_int(offset);
-
-                    vec![base, offset]
-                } else {
-                    panic!("Failed to resolve argument value.");
+                ArgumentType::STACK => {
+                    if let [offset, base] = argument_text.split('(').collect::<Vec<&str>>()[..] {
+                        let base = resolve_register(base.trim_end_matches(')'), false);
+                        let offset = convert_string_to_int(offset);
+
+                        vec![base, offset]
+                    } else {
+                        panic!("Failed to resolve argument value.");
+                    }
                 }
-            }
-        })
-        .collect()
+                ArgumentType::RELOCATION => {
+                    let (relocation_type, symbol) = argument_text[1..argument_text.len() - 1]
+                        .split_once('(')
+                        .map(|(operator, symbol)| match operator {
+                            "hi" => (RelocationType::HI16, symbol),
+                            _ => (RelocationType::LO16, symbol),
+                        })
+                        .unwrap();
+                    let address = resolve_symbol_address(symbol, data, labels, externs);
+                    relocation = Some(Relocation::new(relocation_type, symbol));
+
+                    match relocation_type {
+                        RelocationType::HI16 => vec![((address + 0x8000) >> 16) & 0xffff],
+                        _ => vec![address & 0xffff],
+                    }
+                }
+            },
+        )
+        .collect();
+
+    (arguments, relocation)
+}
+
+fn resolve_symbol_address(name: &str, data: &[Datum], labels: &[Label], externs: &[String]) -> i32 {
+    if let Some(datum) = find_datum(name, data) {
+        datum.address
+    } else if let Some(label) = find_label(name, labels) {
+        label.address
+    } else if externs.iter().any(|extern_name| extern_name == name) {
+        0
+    } else {
+        panic!("Failed to resolve argument value.");
+    }
+}
+
+fn resolve_label_relocation(
+    instruction: &Instruction,
+    name: &str,
+    externs: &[String],
+) -> Option<Relocation> {
+    let is_extern = externs.iter().any(|extern_name| extern_name == name);
+    match convert_opcode_to_format(instruction.opcode, instruction.format_code) {
+        InstructionFormat::JUMP => Some(Relocation::new(RelocationType::JUMP26, name)),
+        InstructionFormat::IMMEDIATE if instruction.is_branch() && is_extern => {
+            Some(Relocation::new(RelocationType::PC16, name))
+        }
+        _ => None,
+    }
+}
+
+fn argument_type_regexes() -> &'static [(Regex, ArgumentType)] {
+    static ARGUMENT_TYPE_REGEXES: OnceLock<[(Regex, ArgumentType); 5]> = OnceLock::new();
+    ARGUMENT_TYPE_REGEXES.get_or_init(|| {
+        [
+            (
+                Regex::new(r"^%(hi|lo)\(\w+\)$").unwrap(),
+                ArgumentType::RELOCATION,
+            ),
+            (Regex::new(r"^\$\w+").unwrap(), ArgumentType::REGISTER),
+            (Regex::new(r"^[A-Za-z_]\w*").unwrap(), ArgumentType::LABEL),
+            (Regex::new(r"^-?\d+\(\$\w+\)").unwrap(), ArgumentType::STACK),
+            (Regex::new(r"^(0x)?\d*").unwrap(), ArgumentType::NUMBER),
+        ]
+    })
 }
 
 fn resolve_argument_type(text: &str) -> ArgumentType {
-    let arguments = [
-        (Regex::new(r"^\$\d*").unwrap(), ArgumentType::REGISTER),
-        (Regex::new(r"^[a-z]\w*").unwrap(), ArgumentType::LABEL),
-        (Regex::new(r"^-?\d+\(\$\d*\)").unwrap(), ArgumentType::STACK),
-        (Regex::new(r"^(0x)?\d*").unwrap(), ArgumentType::NUMBER),
-    ];
-
-    arguments
+    argument_type_regexes()
         .iter()
         .find(|arg| arg.0.is_match(text))
         .expect("Failed to resolve argument.")
@@ -7,7 +7,7 @@ use std::ops::Neg;
 
 type LinesMap<'a> = Map<Lines<BufReader<&'a mut File>>, fn(std::io::Result<String>) -> String>;
 
-pub fn read_lines(file: &mut File) -> LinesMap {
+pub fn read_lines(file: &mut File) -> LinesMap<'_> {
     if file.seek(SeekFrom::Start(0)).is_ok() {
         BufReader::new(file).lines().map(|line| line.unwrap())
     } else {
@@ -17,13 +17,11 @@ pub fn read_lines(file: &mut File) -> LinesMap {
 
 pub fn convert_string_to_int(code: &str) -> i32 {
     if code.starts_with("0x") {
-        i32::from_str_radix(code.trim_start_matches("0x"), 16).unwrap()
+        u32::from_str_radix(code.trim_start_matches("0x"), 16).unwrap() as i32
     } else if code.starts_with('-') {
-        i32::from_str_radix(code.trim_start_matches('-'), 10)
-            .unwrap()
-            .neg()
+        code.trim_start_matches('-').parse::<i32>().unwrap().neg()
     } else {
-        i32::from_str_radix(code, 10).unwrap()
+        code.parse::<i32>().unwrap()
     }
 }
 
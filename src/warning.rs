@@ -0,0 +1,63 @@
+use crate::instruction::{convert_opcode_to_format, InstructionFormat};
+use crate::text::Text;
+
+pub struct Warning {
+    pub line_number: Option<usize>,
+    pub message: String,
+}
+
+impl Warning {
+    pub fn new(line_number: Option<usize>, message: &str) -> Self {
+        Self {
+            line_number,
+            message: message.to_string(),
+        }
+    }
+}
+
+fn is_branch(text: &Text) -> bool {
+    text.get_instruction()
+        .is_some_and(|instruction| instruction.is_branch())
+}
+
+fn check_text(text: &Text) -> Option<String> {
+    if text.relocation.is_some() {
+        return None;
+    }
+
+    match convert_opcode_to_format(text.opcode, text.rs) {
+        InstructionFormat::REGISTER if !(0..32).contains(&text.shamt) => Some(format!(
+            "Shift amount {} does not fit in 5 bits and was truncated.",
+            text.shamt
+        )),
+        InstructionFormat::IMMEDIATE
+            if is_branch(text) && !(-0x8000..0x8000).contains(&text.immediate) =>
+        {
+            Some(format!(
+                "Branch offset {} does not fit in 16 bits and was truncated.",
+                text.immediate
+            ))
+        }
+        InstructionFormat::IMMEDIATE if !(-0x8000..0x10000).contains(&text.immediate) => {
+            Some(format!(
+                "Immediate value {} does not fit in 16 bits and was truncated.",
+                text.immediate
+            ))
+        }
+        _ => None,
+    }
+}
+
+pub fn collect_warnings(texts: &[Text]) -> Vec<Warning> {
+    texts
+        .iter()
+        .filter_map(|text| {
+            check_text(text).map(|message| {
+                Warning::new(
+                    text.source.as_ref().map(|source| source.line_number),
+                    &message,
+                )
+            })
+        })
+        .collect()
+}
@@ -145,3 +145,720 @@ exit:
 ";
 
 pub const OUTPUT_CASE_5: &str = "0000000000000000000000000100110000000000000000000000000000001000000000010100000001010000001001000000000101100000010110000010010000111100000010000001000000000000001111000000100100010000000000000011010100101001000000000000010000100101010010100000000000000001000000000000101001010000010000000000000000001011010110000100000000100101010010100000000000000001001001010110101100000000000000010000000100100000010010000010010100000010010010101001000000100011000000000001000110010000010000000000000000010010100010000100000000000001011111110101100000100001000000100011001010000000001001110001010101101000111111111111011100001000000100000000000000010010001100011110111100000000000011110000000000000000000000010010110000000000000000000000000011001000";
+
+pub const INPUT_CASE_6: &str = "
+	.data
+data1:	.word	100
+data2:	.word	200
+	.word	300
+table:	.word	lab1
+	.text
+	.globl	main
+	.extern	printf
+main:
+	and	$17, $17, $0
+	la	$8, data1
+	la	$9, data2
+lab1:
+	addiu	$17, $17, 0x1
+	bne	$17, $8, lab1
+	jal	printf
+	beq	$17, $8, printf
+	j	lab1
+";
+
+pub const INPUT_CASE_7: &str = "
+	.data
+value:	.word	42
+	.text
+	.globl	__start
+__start:
+	la	$8, value
+	lw	$4, 0($8)
+	addiu	$2, $0, 4001
+	syscall
+";
+
+pub const OUTPUT_CASE_7_IHEX: &str = ":020000040040BA
+:100000003C0810008D04000024020FA10000000C29
+:020000041000EA
+:040000000000002AD2
+:0400000500400000B7
+:00000001FF
+";
+
+pub const OUTPUT_CASE_7_SREC: &str = "S00E00006D69707373656D626C65724E
+S315004000003C0810008D04000024020FA10000000CE3
+S309100000000000002ABC
+S5030002FA
+S70500400000BA
+";
+
+pub const OUTPUT_CASE_7_READMEMH_TEXT: &str = "3c081000
+8d040000
+24020fa1
+0000000c
+00000000
+00000000
+00000000
+00000000
+";
+
+pub const OUTPUT_CASE_7_MIF_DATA: &str = "WIDTH=32;
+DEPTH=8;
+
+ADDRESS_RADIX=HEX;
+DATA_RADIX=HEX;
+
+CONTENT BEGIN
+	0 : 0000002a;
+	[1..7] : 00000000;
+END;
+";
+
+pub const OUTPUT_CASE_7_LOGISIM_TEXT: &str = "v2.0 raw
+4*0 3c081000 8d040000 24020fa1 c 4*0
+";
+
+pub const OUTPUT_CASE_7_C_ARRAY: &str = "#include <stdint.h>
+
+#define TEXT_BASE_ADDRESS 0x00400000u
+#define DATA_BASE_ADDRESS 0x10000000u
+#define ENTRY_ADDRESS 0x00400000u
+#define TEXT_WORD_COUNT 4u
+#define DATA_WORD_COUNT 1u
+
+#define SYMBOL___START 0x00400000u
+
+const uint32_t text[] = {
+    0x3c081000, 0x8d040000, 0x24020fa1, 0x0000000c,
+};
+
+const uint32_t data[] = {
+    0x0000002a,
+};
+";
+
+pub const OUTPUT_CASE_7_RUST_ARRAY: &str = "pub const TEXT_BASE_ADDRESS: u32 = 0x00400000;
+pub const DATA_BASE_ADDRESS: u32 = 0x10000000;
+pub const ENTRY_ADDRESS: u32 = 0x00400000;
+pub const TEXT_WORD_COUNT: u32 = 4;
+pub const DATA_WORD_COUNT: u32 = 1;
+
+pub const SYMBOL___START: u32 = 0x00400000;
+
+pub const TEXT: [u32; 4] = [
+    0x3c081000, 0x8d040000, 0x24020fa1, 0x0000000c,
+];
+
+pub const DATA: [u32; 1] = [
+    0x0000002a,
+];
+";
+
+pub const OUTPUT_CASE_1_LISTING: &str = "Address   Code       Line  Source
+.text
+00400000                7  main:
+00400000  02208824      8  and $17, $17, $0
+00400004  02409024      9  and $18, $18, $0
+00400008  3c081000     10  la $8, data1                    ; la -> lui $8, 4096
+0040000c  3c091000     11  la $9, data2                    ; la -> lui $9, 4096
+00400010  35290004     11  la $9, data2                    ; la -> ori $9, $9, 4
+00400014  01405024     12  and $10, $10, $0
+00400018               13  lab1:
+00400018  01605824     14  and $11, $11, $0
+0040001c               15  lab2:
+0040001c  26310001     16  addiu $17, $17, 0x1
+00400020  256b0001     17  addiu $11, $11, 0x1
+00400024  01204825     18  or $9, $9, $0
+00400028  1568fffc     19  bne $11, $8, lab2
+0040002c               20  lab3:
+0040002c  26520002     21  addiu $18, $18, 0x2
+00400030  256b0001     22  addiu $11, $11, 1
+00400034  00119040     23  sll $18, $17, 1
+00400038  00128842     24  srl $17, $18, 1
+0040003c  02329824     25  and $19, $17, $18
+00400040  1569fffa     26  bne $11, $9, lab3
+00400044               27  lab4:
+00400044  00bf2821     28  addu $5, $5, $31
+00400048  02328027     29  nor $16, $17, $18
+0040004c  11480001     30  beq $10, $8, lab5
+00400050  08100006     31  j lab1
+00400054               32  lab5:
+00400054  3610f0f0     33  ori $16, $16, 0xf0f0
+
+.data
+10000000  00000064      3  data1: .word 100
+10000004  000000c8      4  data2: .word 200
+10000008  12345678      5  data3: .word 0x12345678
+
+Symbols
+Address   Section  Name
+00400000  .text    main
+00400018  .text    lab1
+0040001c  .text    lab2
+0040002c  .text    lab3
+00400044  .text    lab4
+00400054  .text    lab5
+10000000  .data    data1
+10000004  .data    data2
+10000008  .data    data3
+";
+
+pub const OUTPUT_CASE_6_MAP: &str = "Sections
+Name     Start     End       Size
+.text    00400000  00400028  00000028
+.data    10000000  10000010  00000010
+
+Symbols
+Address   Size      Section  Binding  Name    Source
+00400000  00000014  .text    global   main    {input}:10
+00400014  00000014  .text    local    lab1    {input}:14
+10000000  00000004  .data    local    data1   {input}:3
+10000004  00000008  .data    local    data2   {input}:4
+1000000c  00000004  .data    local    table   {input}:6
+00000000  00000000  *UND*    global   printf
+";
+
+pub const OUTPUT_CASE_7_JSON: &str = r#"{
+  "version": 1,
+  "source": "{input}",
+  "entry": 4194304,
+  "sections": [
+    {"name": ".text", "address": 4194304, "size": 16, "words": [1007161344, 2365849600, 604114849, 12]},
+    {"name": ".data", "address": 268435456, "size": 4, "words": [42]}
+  ],
+  "instructions": [
+    {"address": 4194304, "encoding": 1007161344, "mnemonic": "lui", "format": "I", "fields": {"opcode": 15, "rs": 0, "rt": 8, "rd": null, "shamt": null, "funct": null, "immediate": 4096, "target": null}, "target_address": null, "source": {"file": "{input}", "line": 7, "text": "la\t$8, value"}, "pseudo": "la"},
+    {"address": 4194308, "encoding": 2365849600, "mnemonic": "lw", "format": "I", "fields": {"opcode": 35, "rs": 8, "rt": 4, "rd": null, "shamt": null, "funct": null, "immediate": 0, "target": null}, "target_address": null, "source": {"file": "{input}", "line": 8, "text": "lw\t$4, 0($8)"}, "pseudo": null},
+    {"address": 4194312, "encoding": 604114849, "mnemonic": "addiu", "format": "I", "fields": {"opcode": 9, "rs": 0, "rt": 2, "rd": null, "shamt": null, "funct": null, "immediate": 4001, "target": null}, "target_address": null, "source": {"file": "{input}", "line": 9, "text": "addiu\t$2, $0, 4001"}, "pseudo": null},
+    {"address": 4194316, "encoding": 12, "mnemonic": "syscall", "format": "R", "fields": {"opcode": 0, "rs": 0, "rt": 0, "rd": 0, "shamt": 0, "funct": 12, "immediate": null, "target": null}, "target_address": null, "source": {"file": "{input}", "line": 10, "text": "syscall"}, "pseudo": null}
+  ],
+  "symbols": [
+    {"name": "__start", "address": 4194304, "size": 16, "section": ".text", "binding": "global", "line": 6},
+    {"name": "value", "address": 268435456, "size": 4, "section": ".data", "binding": "local", "line": 3}
+  ],
+  "warnings": []
+}
+"#;
+
+pub const INPUT_CASE_8: &str = "
+	.text
+main:
+	addiu	$8, $0, 70000
+	sll	$9, $8, 33
+";
+
+pub const OUTPUT_CASE_1_DISASM: &str = "\t.data
+data_10000000:\t.word\t100
+\t.word\t200
+\t.word\t305419896
+\t.text
+\tand\t$s1, $s1, $zero
+\tand\t$s2, $s2, $zero
+\tlui\t$t0, 0x1000
+\tlui\t$t1, 0x1000
+\tori\t$t1, $t1, 0x4
+\tand\t$t2, $t2, $zero
+label_00400018:
+\tand\t$t3, $t3, $zero
+label_0040001c:
+\taddiu\t$s1, $s1, 1
+\taddiu\t$t3, $t3, 1
+\tor\t$t1, $t1, $zero
+\tbne\t$t3, $t0, label_0040001c
+label_0040002c:
+\taddiu\t$s2, $s2, 2
+\taddiu\t$t3, $t3, 1
+\tsll\t$s2, $s1, 1
+\tsrl\t$s1, $s2, 1
+\tand\t$s3, $s1, $s2
+\tbne\t$t3, $t1, label_0040002c
+\taddu\t$a1, $a1, $ra
+\tnor\t$s0, $s1, $s2
+\tbeq\t$t2, $t0, label_00400054
+\tj\tlabel_00400018
+label_00400054:
+\tori\t$s0, $s0, 0xf0f0
+";
+
+pub const INPUT_CASE_9: &str = "\t.data
+data1:\t.word\t100
+data2:\t.word\t200
+\t.text
+main:
+\tla\t$t0, data1
+\tla\t$t1, data2\t# second
+\tmove\t$t2, $t1
+\tnop
+
+\taddu\t$t3, $t0, $t1
+\tj\tmain
+";
+
+pub const OUTPUT_CASE_9_DISASM_PSEUDO: &str = "\t.data
+data_10000000:\t.word\t100
+data_10000004:\t.word\t200
+\t.text
+label_00400000:
+\tla\t$t0, data_10000000\t# 0x3c081000
+\tla\t$t1, data_10000004\t# 0x3c091000 0x35290004
+\tmove\t$t2, $t1\t# 0x01205021
+\tnop\t# 0x00000000
+\taddu\t$t3, $t0, $t1
+\tj\tlabel_00400000
+";
+
+pub const INPUT_CASE_10: &str = "\t.data
+values:\t.word\t5
+\t.word\t-7
+\t.text
+main:
+\tla\t$t0, values
+\tlw\t$t1, 0($t0)
+\tlw\t$t2, 4($t0)
+\tmult\t$t1, $t2
+\tmflo\t$s0
+\tlh\t$s1, 6($t0)
+\taddiu\t$a0, $zero, 10
+loop:
+\taddu\t$v0, $v0, $a0
+\taddiu\t$a0, $a0, -1
+\tbgtz\t$a0, loop
+\tjal\tfunc
+\tj\tend
+func:
+\taddiu\t$v1, $zero, 77
+\tjr\t$ra
+end:
+";
+
+pub const INPUT_CASE_11: &str = "\t.text
+main:
+\tlui\t$t0, 0x7fff
+\tori\t$t0, $t0, 0xffff
+\taddi\t$t1, $t0, 1
+";
+
+pub const INPUT_CASE_12: &str = "\t.text
+main:
+\tlui\t$t0, 0x1000
+\tlw\t$t1, 2($t0)
+";
+
+pub const INPUT_CASE_13: &str = "\t.data
+prompt:\t.word\t0x53756d3a
+\t.word\t0x20000000
+name:\t.word\t0x6f75742e
+\t.word\t0x74787400
+\t.text
+main:
+\taddiu\t$v0, $zero, 5
+\tsyscall
+\tmove\t$t0, $v0
+\taddiu\t$v0, $zero, 5
+\tsyscall
+\taddu\t$t0, $t0, $v0
+\tla\t$a0, prompt
+\taddiu\t$v0, $zero, 4
+\tsyscall
+\tmove\t$a0, $t0
+\taddiu\t$v0, $zero, 1
+\tsyscall
+\taddiu\t$a0, $zero, 10
+\taddiu\t$v0, $zero, 11
+\tsyscall
+\taddiu\t$a0, $zero, 6
+\taddiu\t$v0, $zero, 9
+\tsyscall
+\tmove\t$s0, $v0
+\tla\t$a0, name
+\taddiu\t$a1, $zero, 1
+\taddiu\t$v0, $zero, 13
+\tsyscall
+\tmove\t$s1, $v0
+\tmove\t$a0, $s1
+\tla\t$a1, prompt
+\taddiu\t$a2, $zero, 5
+\taddiu\t$v0, $zero, 15
+\tsyscall
+\tmove\t$a0, $s1
+\taddiu\t$v0, $zero, 16
+\tsyscall
+\taddiu\t$a0, $zero, 3
+\taddiu\t$v0, $zero, 17
+\tsyscall
+";
+
+pub const INPUT_CASE_14: &str = "\t.data
+count:\t.word\t0
+\t.text
+main:
+\tla\t$t0, count
+\taddiu\t$t1, $zero, 3
+\tsw\t$t1, 0($t0)
+\taddiu\t$t1, $t1, 1
+\tsw\t$t1, 0($t0)
+";
+
+pub const INPUT_CASE_15: &str = "\t.data
+values:\t.word\t3
+\t.word\t4
+\t.text
+main:
+\tla\t$t0, values
+\tlw\t$t1, 0($t0)
+\tlw\t$t2, 4($t0)
+\tadd\t$t3, $t1, $t2
+\tsub\t$t4, $t3, $t1
+\taddiu\t$a0, $zero, 2
+loop:
+\taddiu\t$a0, $a0, -1
+\tbne\t$a0, $zero, loop
+\tsw\t$t4, 0($t0)
+";
+
+pub const OUTPUT_CASE_15_PIPELINE: &str = "Cycle  IF                      ID                      EX                      MEM                     WB                      Notes
+    1  lui $t0, 0x1000
+    2  lw $t1, 0($t0)          lui $t0, 0x1000
+    3  lw $t2, 4($t0)          lw $t1, 0($t0)          lui $t0, 0x1000
+    4  add $t3, $t1, $t2       lw $t2, 4($t0)          lw $t1, 0($t0)          lui $t0, 0x1000                                 forward $t0 EX/MEM -> EX
+    5  sub $t4, $t3, $t1       add $t3, $t1, $t2       lw $t2, 4($t0)          lw $t1, 0($t0)          lui $t0, 0x1000         forward $t0 MEM/WB -> EX, load-use stall on $t2
+    6  sub $t4, $t3, $t1       add $t3, $t1, $t2                               lw $t2, 4($t0)          lw $t1, 0($t0)
+    7  addiu $a0, $zero, 2     sub $t4, $t3, $t1       add $t3, $t1, $t2                               lw $t2, 4($t0)          forward $t2 MEM/WB -> EX
+    8  addiu $a0, $a0, -1      addiu $a0, $zero, 2     sub $t4, $t3, $t1       add $t3, $t1, $t2                               forward $t3 EX/MEM -> EX
+    9  bne $a0, $zero, loop    addiu $a0, $a0, -1      addiu $a0, $zero, 2     sub $t4, $t3, $t1       add $t3, $t1, $t2
+   10  sw $t4, 0($t0)          bne $a0, $zero, loop    addiu $a0, $a0, -1      addiu $a0, $zero, 2     sub $t4, $t3, $t1       forward $a0 EX/MEM -> EX, data stall on $a0
+   11  sw $t4, 0($t0)          bne $a0, $zero, loop                            addiu $a0, $a0, -1      addiu $a0, $zero, 2     forward $a0 EX/MEM -> ID, flush sw $t4, 0($t0)
+   12  addiu $a0, $a0, -1                              bne $a0, $zero, loop                            addiu $a0, $a0, -1
+   13  bne $a0, $zero, loop    addiu $a0, $a0, -1                              bne $a0, $zero, loop
+   14  sw $t4, 0($t0)          bne $a0, $zero, loop    addiu $a0, $a0, -1                              bne $a0, $zero, loop    data stall on $a0
+   15  sw $t4, 0($t0)          bne $a0, $zero, loop                            addiu $a0, $a0, -1                              forward $a0 EX/MEM -> ID
+   16                          sw $t4, 0($t0)          bne $a0, $zero, loop                            addiu $a0, $a0, -1
+   17                                                  sw $t4, 0($t0)          bne $a0, $zero, loop
+   18                                                                          sw $t4, 0($t0)          bne $a0, $zero, loop
+   19                                                                                                  sw $t4, 0($t0)
+Instructions: 11
+Cycles: 19
+CPI: 1.73
+Stalls: 2 data, 1 load-use, 2 control
+";
+
+pub const INPUT_CASE_16: &str = "\t.data
+values:\t.word\t3
+\t.text
+main:
+\tla\t$t0, values
+\tlw\t$t1, 0($t0)
+\tadd\t$t2, $t1, $t1
+\tsub\t$t3, $t2, $t1
+\taddiu\t$a0, $zero, 2
+loop:
+\taddiu\t$a0, $a0, -1
+\tbne\t$a0, $zero, loop
+\tsw\t$t3, 0($t0)
+";
+
+pub const INPUT_CASE_17: &str = "\t.text
+main:
+\taddiu\t$t0, $zero, 0
+\taddiu\t$t1, $zero, 5
+loop:
+\taddiu\t$t0, $t0, 1
+\taddu\t$t2, $t2, $t0
+\tbne\t$t0, $t1, loop
+\taddu\t$a0, $t2, $zero
+\tjal\tprint
+\taddiu\t$v0, $zero, 10
+\tsyscall
+
+\t.set\tnoreorder
+print:
+\tjr\t$ra
+\taddiu\t$v0, $zero, 1
+\t.set\treorder
+";
+
+pub const OUTPUT_CASE_17_DISASSEMBLY: &str = "\t.text
+\taddiu\t$t0, $zero, 0
+\taddiu\t$t1, $zero, 5
+label_00400008:
+\taddiu\t$t0, $t0, 1
+\tbne\t$t0, $t1, label_00400008
+\taddu\t$t2, $t2, $t0
+\tjal\tlabel_00400024
+\taddu\t$a0, $t2, $zero
+\taddiu\t$v0, $zero, 10
+\tsyscall
+label_00400024:
+\tjr\t$ra
+\taddiu\t$v0, $zero, 1
+";
+
+pub const INPUT_CASE_18: &str = "\t.data
+array:\t.word\t1
+\t.word\t2
+\t.word\t3
+\t.word\t4
+\t.word\t5
+\t.word\t6
+\t.word\t7
+\t.word\t8
+\t.text
+main:
+\tla\t$t0, array
+\taddiu\t$t1, $zero, 8
+\taddu\t$v0, $zero, $zero
+loop:
+\tlw\t$t2, 0($t0)
+\taddu\t$v0, $v0, $t2
+\tsw\t$v0, 0($t0)
+\taddiu\t$t0, $t0, 4
+\taddiu\t$t1, $t1, -1
+\tbne\t$t1, $zero, loop
+";
+
+pub const INPUT_CASE_19: &str = "\t.text
+main:
+\tlui\t$s0, 0xffff
+\tlui\t$s1, 0x1004
+\taddiu\t$t0, $zero, 10
+\tsw\t$t0, 20($s0)
+\taddiu\t$t0, $zero, 2
+\tsw\t$t0, 24($s0)
+echo:
+\tlw\t$t0, 0($s0)
+\tandi\t$t0, $t0, 1
+\tbeq\t$t0, $zero, done
+\tlw\t$t1, 4($s0)
+wait:
+\tlw\t$t0, 8($s0)
+\tandi\t$t0, $t0, 1
+\tbeq\t$t0, $zero, wait
+\tsw\t$t1, 12($s0)
+\tsw\t$t1, 0($s1)
+\taddiu\t$s1, $s1, 4
+\tj\techo
+done:
+\tlw\t$s2, 24($s0)
+\tlw\t$s3, 16($s0)
+";
+
+pub const INPUT_CASE_20: &str = "\t.kdata
+count:\t.word\t0
+\t.ktext
+handler:
+\tmfc0\t$k0, $13
+\tandi\t$k0, $k0, 0x7c
+\tbeq\t$k0, $zero, interrupt
+\tmfc0\t$s2, $13
+\tmfc0\t$s3, $8
+\tla\t$k1, count
+\tlw\t$k0, 0($k1)
+\taddiu\t$k0, $k0, 1
+\tsw\t$k0, 0($k1)
+\tmfc0\t$k0, $14
+\taddiu\t$k0, $k0, 4
+\tmtc0\t$k0, $14
+\teret
+interrupt:
+\taddiu\t$s1, $s1, 1
+\tlui\t$k1, 0xffff
+\taddiu\t$k0, $zero, 0
+\tsw\t$k0, 24($k1)
+\teret
+\t.text
+main:
+\tlui\t$t4, 0xffff
+\taddiu\t$t5, $zero, 5
+\tsw\t$t5, 20($t4)
+\taddiu\t$t5, $zero, 2
+\tsw\t$t5, 24($t4)
+wait:
+\tbeq\t$s1, $zero, wait
+\tlui\t$t0, 0x7fff
+\tori\t$t0, $t0, 0xffff
+\tadd\t$t1, $t0, $t0
+\tlw\t$t2, 1($zero)
+\tla\t$t3, count
+\tlw\t$s0, 0($t3)
+";
+
+pub const INPUT_CASE_21: &str = "\t.data
+half:\t.float\t0.5
+pi:\t.double\t3.141592653589793
+count:\t.word\t7
+result:\t.double\t0.0
+single:\t.float\t0.0
+\t.text
+main:
+\tla\t$t0, half
+\tlwc1\t$f0, 0($t0)
+\tla\t$t1, pi
+\tldc1\t$f2, 0($t1)
+\tcvt.d.s\t$f4, $f0
+\tmul.d\t$f6, $f2, $f4
+\tadd.d\t$f6, $f6, $f6
+\tla\t$t2, result
+\tsdc1\t$f6, 0($t2)
+\tc.eq.d\t$f6, $f2
+\tbc1f\tskip
+\taddiu\t$s0, $zero, 1
+skip:
+\taddiu\t$t3, $zero, -3
+\tmtc1\t$t3, $f8
+\tcvt.s.w\t$f8, $f8
+\tneg.s\t$f8, $f8
+\tdiv.s\t$f10, $f8, $f0
+\tcvt.w.s\t$f12, $f10
+\tmfc1\t$s1, $f12
+\tsub.s\t$f14, $f10, $f8
+\tc.lt.s\t$f10, $f14
+\tbc1t\tless
+\taddiu\t$s2, $zero, 9
+less:
+\tmov.s\t$f16, $f14
+\tabs.d\t$f18, $f6
+\tcvt.s.d\t$f20, $f18
+\tla\t$t4, single
+\tswc1\t$f20, 0($t4)
+\tlw\t$s3, 0($t4)
+\tlw\t$s4, 0($t2)
+\tlw\t$s5, 4($t2)
+";
+
+pub const OUTPUT_CASE_21_DISASM: &str = "\t.data
+data_10000000:\t.word\t1056964608
+\t.word\t0
+\t.word\t1074340347
+\t.word\t1413754136
+\t.word\t7
+\t.word\t0
+\t.word\t0
+\t.word\t0
+\t.word\t0
+\t.text
+\tlui\t$t0, 0x1000
+\tlwc1\t$f0, 0($t0)
+\tlui\t$t1, 0x1000
+\tori\t$t1, $t1, 0x8
+\tldc1\t$f2, 0($t1)
+\tcvt.d.s\t$f4, $f0
+\tmul.d\t$f6, $f2, $f4
+\tadd.d\t$f6, $f6, $f6
+\tlui\t$t2, 0x1000
+\tori\t$t2, $t2, 0x18
+\tsdc1\t$f6, 0($t2)
+\tc.eq.d\t$f6, $f2
+\tbc1f\tlabel_00400038
+\taddiu\t$s0, $zero, 1
+label_00400038:
+\taddiu\t$t3, $zero, -3
+\tmtc1\t$t3, $f8
+\tcvt.s.w\t$f8, $f8
+\tneg.s\t$f8, $f8
+\tdiv.s\t$f10, $f8, $f0
+\tcvt.w.s\t$f12, $f10
+\tmfc1\t$s1, $f12
+\tsub.s\t$f14, $f10, $f8
+\tc.lt.s\t$f10, $f14
+\tbc1t\tlabel_00400064
+\taddiu\t$s2, $zero, 9
+label_00400064:
+\tmov.s\t$f16, $f14
+\tabs.d\t$f18, $f6
+\tcvt.s.d\t$f20, $f18
+\tlui\t$t4, 0x1000
+\tori\t$t4, $t4, 0x20
+\tswc1\t$f20, 0($t4)
+\tlw\t$s3, 0($t4)
+\tlw\t$s4, 0($t2)
+\tlw\t$s5, 4($t2)
+";
+
+pub const INPUT_CASE_22: &str = "\t.text
+main:
+\taddiu\t$a0, $zero, 16
+\taddiu\t$v0, $zero, 9
+\tsyscall
+\tsw\t$zero, 0($v0)
+\taddu\t$s0, $v0, $zero
+";
+
+pub const INPUT_CASE_23: &str = "\t.data
+one:\t.double\t1.0
+\t.text
+main:
+\tsyscall
+";
+
+pub const INPUT_CASE_24: &str = "\t.text
+main:
+\tadd.s\t$f0, $t0, $f2
+";
+
+pub const INPUT_CASE_25: &str = "\t.text
+main:
+\tmtc1\t$f0, $t0
+";
+
+pub const INPUT_CASE_26: &str = "\t.text
+main:
+\taddiu\t$a0, $zero, 1
+\taddiu\t$a1, $zero, 0
+\taddiu\t$a2, $zero, -1
+\taddiu\t$v0, $zero, 15
+\tsyscall
+\taddu\t$s0, $v0, $zero
+\taddiu\t$a0, $zero, 0
+\tlui\t$a1, 0x1001
+\taddiu\t$v0, $zero, 14
+\tsyscall
+\taddu\t$s1, $v0, $zero
+\taddiu\t$a0, $zero, 99
+\taddiu\t$a2, $zero, 4
+\taddiu\t$v0, $zero, 15
+\tsyscall
+\taddu\t$s2, $v0, $zero
+";
+
+pub const INPUT_CASE_27: &str = "\t.text
+\t.globl\t__start
+__start:
+\taddiu\t$16, $0, 0
+\taddiu\t$17, $0, 5
+loop:
+\tjal\tadd_seven
+\taddiu\t$17, $17, -1
+\tbne\t$17, $0, loop
+\taddu\t$4, $16, $0
+\taddiu\t$2, $0, 4001
+\tsyscall
+add_seven:
+\taddiu\t$16, $16, 7
+\tjr\t$31
+";
+
+pub const INPUT_CASE_28: &str = "# Same program as INPUT_CASE_15, with comments and blank lines
+\t.data
+
+# operands
+values:\t.word\t3\t# first
+
+\t.word\t4
+\t.text
+main:
+\tla\t$t0, values\t# base address
+\tlw\t$t1, 0($t0)
+
+\tlw\t$t2, 4($t0)
+# sum and difference
+\tadd\t$t3, $t1, $t2
+\tsub\t$t4, $t3, $t1
+\taddiu\t$a0, $zero, 2
+
+loop:
+\taddiu\t$a0, $a0, -1\t# count down
+\tbne\t$a0, $zero, loop
+\tsw\t$t4, 0($t0)
+";
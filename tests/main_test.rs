@@ -1,3 +1,5 @@
+#![allow(clippy::needless_borrows_for_generic_args)]
+
 use assert_cmd::prelude::*;
 use std::io::{Read, Write};
 use std::process::Command;
@@ -111,3 +113,1490 @@ fn test_main_case_5() {
 
     assert_eq!(actual, OUTPUT_CASE_5);
 }
+
+#[test]
+fn test_main_bin_format() {
+    use fixtures::{INPUT_CASE_1, OUTPUT_CASE_1};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_1.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "bin"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = vec![];
+    output_file.read_to_end(&mut actual).unwrap();
+
+    let mut expected = b"MIPS\x02\0\0\0".to_vec();
+    expected.extend(
+        OUTPUT_CASE_1
+            .as_bytes()
+            .chunks(8)
+            .map(|bits| u8::from_str_radix(std::str::from_utf8(bits).unwrap(), 2).unwrap()),
+    );
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_main_bin_format_little_endian() {
+    use fixtures::{INPUT_CASE_1, OUTPUT_CASE_1};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_1.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "bin", "-EL"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = vec![];
+    output_file.read_to_end(&mut actual).unwrap();
+
+    let mut expected = b"MIPS\x01\0\0\0".to_vec();
+    expected.extend(OUTPUT_CASE_1.as_bytes().chunks(32).flat_map(|bits| {
+        u32::from_str_radix(std::str::from_utf8(bits).unwrap(), 2)
+            .unwrap()
+            .to_le_bytes()
+            .to_vec()
+    }));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_main_elf_format() {
+    use fixtures::INPUT_CASE_6;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_6.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "elf"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = vec![];
+    output_file.read_to_end(&mut actual).unwrap();
+
+    let half = |offset: usize| u16::from_be_bytes([actual[offset], actual[offset + 1]]);
+    let word = |offset: usize| {
+        u32::from_be_bytes([
+            actual[offset],
+            actual[offset + 1],
+            actual[offset + 2],
+            actual[offset + 3],
+        ])
+    };
+    let section_header = |index: usize| word(0x20) as usize + index * 40;
+
+    assert_eq!(&actual[..7], b"\x7fELF\x01\x02\x01");
+    assert_eq!(half(0x10), 1);
+    assert_eq!(half(0x12), 8);
+    assert_eq!(word(0x24), 0x5000_1000);
+    assert_eq!(half(0x30), 9);
+
+    let text_offset = word(section_header(1) + 0x10) as usize;
+    assert_eq!(word(section_header(1) + 0x14), 10 * 4);
+    assert_eq!(word(text_offset + 0x04), 0x3c08_0000);
+    assert_eq!(word(text_offset + 0x08), 0x2508_0000);
+    assert_eq!(word(text_offset + 0x1c), 0x0c00_0000);
+    assert_eq!(word(text_offset + 0x20), 0x1228_ffff);
+
+    let relocation_offset = word(section_header(6) + 0x10) as usize;
+    let relocations = (0..7)
+        .map(|index| {
+            let entry = relocation_offset + index * 8;
+            (word(entry), word(entry + 4) & 0xff)
+        })
+        .collect::<Vec<(u32, u32)>>();
+    assert_eq!(
+        relocations,
+        vec![
+            (0x04, 5),
+            (0x08, 6),
+            (0x0c, 5),
+            (0x10, 6),
+            (0x1c, 4),
+            (0x20, 10),
+            (0x24, 4)
+        ]
+    );
+    assert_eq!(word(section_header(7) + 0x14), 8);
+
+    let symbol_offset = word(section_header(4) + 0x10) as usize;
+    let data_symbol_sizes = (5..8)
+        .map(|index| word(symbol_offset + index * 16 + 0x08))
+        .collect::<Vec<u32>>();
+    assert_eq!(data_symbol_sizes, vec![4, 8, 4]);
+}
+
+#[test]
+fn test_main_elf_exec_format() {
+    use fixtures::INPUT_CASE_7;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_7.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "elf-exec", "-EL"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = vec![];
+    output_file.read_to_end(&mut actual).unwrap();
+
+    let half = |offset: usize| u16::from_le_bytes([actual[offset], actual[offset + 1]]);
+    let word = |offset: usize| {
+        u32::from_le_bytes([
+            actual[offset],
+            actual[offset + 1],
+            actual[offset + 2],
+            actual[offset + 3],
+        ])
+    };
+    let program_header = |index: usize| word(0x1c) as usize + index * 32;
+
+    assert_eq!(&actual[..7], b"\x7fELF\x01\x01\x01");
+    assert_eq!(half(0x10), 2);
+    assert_eq!(word(0x18), 0x0040_0000);
+    assert_eq!(half(0x2c), 2);
+
+    let text_segment = program_header(0);
+    assert_eq!(word(text_segment), 1);
+    assert_eq!(word(text_segment + 0x08), 0x0040_0000);
+    assert_eq!(word(text_segment + 0x10), 4 * 4);
+    assert_eq!(word(text_segment + 0x18), 0x5);
+
+    let data_segment = program_header(1);
+    assert_eq!(word(data_segment + 0x08), 0x1000_0000);
+    assert_eq!(word(data_segment + 0x10), 4);
+    assert_eq!(word(data_segment + 0x18), 0x6);
+
+    let text_offset = word(text_segment + 0x04) as usize;
+    assert_eq!(word(text_offset), 0x3c08_1000);
+    assert_eq!(word(text_offset + 0x0c), 0x0000_000c);
+    assert_eq!(word(word(data_segment + 0x04) as usize), 42);
+}
+
+#[test]
+fn test_main_elf_exec_delay_slots() {
+    use fixtures::INPUT_CASE_27;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_27.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "elf-exec"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = vec![];
+    output_file.read_to_end(&mut actual).unwrap();
+
+    let word = |offset: usize| {
+        u32::from_be_bytes([
+            actual[offset],
+            actual[offset + 1],
+            actual[offset + 2],
+            actual[offset + 3],
+        ])
+    };
+    let text_segment = word(0x1c) as usize;
+    let text_offset = word(text_segment + 0x04) as usize;
+
+    assert_eq!(word(0x24), 0x5000_1001);
+    assert_eq!(word(text_segment + 0x10), 12 * 4);
+    assert_eq!(word(text_offset + 0x0c), 0);
+    assert_eq!(word(text_offset + 0x14), 0x1620_fffc);
+    assert_eq!(word(text_offset + 0x18), 0);
+    assert_eq!(word(text_offset + 0x28), 0x03e0_0008);
+    assert_eq!(word(text_offset + 0x2c), 0x2610_0007);
+
+    let disassembly_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("disasm")
+        .args([output_file.path(), disassembly_file.path()])
+        .assert()
+        .success();
+
+    let mut reassembled_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "elf-exec"])
+        .args([disassembly_file.path(), reassembled_file.path()])
+        .assert()
+        .success();
+
+    let mut reassembled = vec![];
+    reassembled_file.read_to_end(&mut reassembled).unwrap();
+
+    assert_eq!(reassembled, actual);
+}
+
+#[test]
+fn test_main_ihex_format() {
+    use fixtures::{INPUT_CASE_7, OUTPUT_CASE_7_IHEX};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_7.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "ihex"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    output_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_7_IHEX);
+}
+
+#[test]
+fn test_main_srec_format() {
+    use fixtures::{INPUT_CASE_7, OUTPUT_CASE_7_SREC};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_7.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "srec"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    output_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_7_SREC);
+}
+
+#[test]
+fn test_main_readmemh_format() {
+    use fixtures::{INPUT_CASE_7, OUTPUT_CASE_7_READMEMH_TEXT};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_7.as_bytes()).unwrap();
+
+    ["zero", "nop"].iter().for_each(|fill| {
+        let output_dir = tempfile::tempdir().unwrap();
+
+        Command::cargo_bin(BIN_NAME)
+            .unwrap()
+            .args([
+                "--format",
+                "readmemh",
+                "--memory-depth",
+                "8",
+                "--fill",
+                fill,
+            ])
+            .arg(input_file.path())
+            .arg(output_dir.path().join("memory.hex"))
+            .assert()
+            .success();
+
+        let actual = std::fs::read_to_string(output_dir.path().join("memory.text.hex")).unwrap();
+
+        assert_eq!(actual, OUTPUT_CASE_7_READMEMH_TEXT);
+    });
+}
+
+#[test]
+fn test_main_mif_format() {
+    use fixtures::{INPUT_CASE_7, OUTPUT_CASE_7_MIF_DATA};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_7.as_bytes()).unwrap();
+
+    let output_dir = tempfile::tempdir().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "mif", "--memory-depth", "8"])
+        .arg(input_file.path())
+        .arg(output_dir.path().join("memory.mif"))
+        .assert()
+        .success();
+
+    let actual = std::fs::read_to_string(output_dir.path().join("memory.data.mif")).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_7_MIF_DATA);
+}
+
+#[test]
+fn test_main_logisim_format() {
+    use fixtures::{INPUT_CASE_7, OUTPUT_CASE_7_LOGISIM_TEXT};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_7.as_bytes()).unwrap();
+
+    let output_dir = tempfile::tempdir().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "logisim", "--address-width", "4"])
+        .args(["--base-offset", "4", "--memory-depth", "12"])
+        .arg(input_file.path())
+        .arg(output_dir.path().join("rom"))
+        .assert()
+        .success();
+
+    let actual = std::fs::read_to_string(output_dir.path().join("rom.text")).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_7_LOGISIM_TEXT);
+}
+
+#[test]
+fn test_main_c_array_format() {
+    use fixtures::{INPUT_CASE_7, OUTPUT_CASE_7_C_ARRAY};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_7.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "c-array"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    output_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_7_C_ARRAY);
+}
+
+#[test]
+fn test_main_rust_array_format() {
+    use fixtures::{INPUT_CASE_7, OUTPUT_CASE_7_RUST_ARRAY};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_7.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "rust-array"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    output_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_7_RUST_ARRAY);
+}
+
+#[test]
+fn test_main_listing() {
+    use fixtures::{INPUT_CASE_1, OUTPUT_CASE_1, OUTPUT_CASE_1_LISTING};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_1.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+    let mut listing_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("-l")
+        .arg(listing_file.path())
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    output_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_1);
+
+    let mut actual = String::new();
+    listing_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_1_LISTING);
+}
+
+#[test]
+fn test_main_map() {
+    use fixtures::{INPUT_CASE_6, OUTPUT_CASE_6_MAP};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_6.as_bytes()).unwrap();
+
+    let output_file = NamedTempFile::new().unwrap();
+    let mut map_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "elf"])
+        .arg("-Map")
+        .arg(map_file.path())
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    map_file.read_to_string(&mut actual).unwrap();
+
+    let expected = OUTPUT_CASE_6_MAP.replace("{input}", input_file.path().to_str().unwrap());
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_main_json_format() {
+    use fixtures::{INPUT_CASE_7, OUTPUT_CASE_7_JSON};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_7.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "json"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    output_file.read_to_string(&mut actual).unwrap();
+
+    let expected = OUTPUT_CASE_7_JSON.replace("{input}", input_file.path().to_str().unwrap());
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_main_warnings() {
+    use fixtures::INPUT_CASE_8;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_8.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "json"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains(":4: Immediate value 70000 does not fit in 16 bits and was truncated."));
+    assert!(stderr.contains(":5: Shift amount 33 does not fit in 5 bits and was truncated."));
+
+    let mut actual = String::new();
+    output_file.read_to_string(&mut actual).unwrap();
+
+    assert!(actual.contains(
+        "{\"line\": 4, \"message\": \"Immediate value 70000 does not fit in 16 bits and was truncated.\"}"
+    ));
+}
+
+#[test]
+fn test_main_disasm_round_trip() {
+    use fixtures::{INPUT_CASE_1, OUTPUT_CASE_1, OUTPUT_CASE_1_DISASM};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_1.as_bytes()).unwrap();
+
+    let output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut disassembly_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("disasm")
+        .args([output_file.path(), disassembly_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    disassembly_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_1_DISASM);
+
+    let mut reassembled_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args([disassembly_file.path(), reassembled_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    reassembled_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_1);
+}
+
+#[test]
+fn test_main_disasm_elf_exec() {
+    use fixtures::INPUT_CASE_7;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_7.as_bytes()).unwrap();
+
+    let output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "elf-exec"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("disasm")
+        .arg(output_file.path())
+        .assert()
+        .success();
+
+    let actual = String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert_eq!(
+        actual,
+        "\t.data\nvalue:\t.word\t42\n\t.text\n\t.set\tnoreorder\n\t.globl\t__start\n__start:\n\tlui\t$t0, 0x1000\n\
+\tlw\t$a0, 0($t0)\n\taddiu\t$v0, $zero, 4001\n\tsyscall\n"
+    );
+}
+
+#[test]
+fn test_main_disasm_pseudo() {
+    use fixtures::{INPUT_CASE_9, OUTPUT_CASE_9_DISASM_PSEUDO};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_9.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut disassembly_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["disasm", "--pseudo"])
+        .args([output_file.path(), disassembly_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    disassembly_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_9_DISASM_PSEUDO);
+
+    let mut reassembled_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args([disassembly_file.path(), reassembled_file.path()])
+        .assert()
+        .success();
+
+    let mut expected = String::new();
+    output_file.read_to_string(&mut expected).unwrap();
+    let mut actual = String::new();
+    reassembled_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_main_run() {
+    use fixtures::INPUT_CASE_10;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_10.as_bytes()).unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("Program finished after 41 steps."));
+    assert!(stderr.contains("$v0   = 0x00000037  $v1   = 0x0000004d"));
+    assert!(stderr.contains("$t0   = 0x10000000  $t1   = 0x00000005  $t2   = 0xfffffff9"));
+    assert!(stderr.contains("$s0   = 0xffffffdd  $s1   = 0xfffffff9"));
+    assert!(stderr.contains("$gp   = 0x10008000  $sp   = 0x7ffffffc"));
+}
+
+#[test]
+fn test_main_run_overflow() {
+    use fixtures::INPUT_CASE_11;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_11.as_bytes()).unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg(input_file.path())
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("Exception at 0x00400008: arithmetic overflow."));
+    assert!(stderr.contains("$t1   = 0x00000000"));
+}
+
+#[test]
+fn test_main_run_unaligned_load() {
+    use fixtures::INPUT_CASE_12;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_12.as_bytes()).unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg(input_file.path())
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("Exception at 0x00400004: address error on load from 0x10000002."));
+}
+
+#[test]
+fn test_main_run_syscalls() {
+    use fixtures::INPUT_CASE_13;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_13.as_bytes()).unwrap();
+
+    let sandbox = tempfile::tempdir().unwrap();
+
+    let assert = assert_cmd::Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg("--sandbox")
+        .arg(sandbox.path())
+        .arg(input_file.path())
+        .write_stdin("4\n-10\n")
+        .assert()
+        .code(3)
+        .stdout("Sum: -6\n");
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("Program exited with code 3 after 36 steps."));
+    assert!(stderr.contains("$s0   = 0x10040000  $s1   = 0x00000003"));
+
+    let actual = std::fs::read_to_string(sandbox.path().join("out.txt")).unwrap();
+    assert_eq!(actual, "Sum: ");
+}
+
+#[test]
+fn test_main_run_sandbox_escape() {
+    use fixtures::INPUT_CASE_13;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file
+        .write_all(INPUT_CASE_13.replace("0x6f75742e", "0x2e2e2f6f").as_bytes())
+        .unwrap();
+
+    let sandbox = tempfile::tempdir().unwrap();
+
+    let assert = assert_cmd::Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg("--sandbox")
+        .arg(sandbox.path())
+        .arg(input_file.path())
+        .write_stdin("1\n2\n")
+        .assert()
+        .code(3);
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("$s1   = 0xffffffff"));
+}
+
+#[test]
+fn test_main_run_file_syscall_limits() {
+    use fixtures::INPUT_CASE_26;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_26.as_bytes()).unwrap();
+
+    let assert = assert_cmd::Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg(input_file.path())
+        .write_stdin("input")
+        .assert()
+        .success()
+        .stdout("");
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("$s0   = 0xffffffff  $s1   = 0xffffffff  $s2   = 0xffffffff"));
+}
+
+#[test]
+fn test_main_debug() {
+    use fixtures::INPUT_CASE_10;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_10.as_bytes()).unwrap();
+
+    let assert = assert_cmd::Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("debug")
+        .arg(input_file.path())
+        .write_stdin("break loop\ncontinue\ncontinue\nprint $a0\nback 2\nprint $a0\ndelete loop\nb 17\nc\nnext\np $v1\nc\nquit\n")
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.starts_with("main:\n00400000      6  la $t0, values\n"));
+    assert!(stdout.contains(
+        "(debug) Breakpoint at 0x0040001c.\nloop:\n0040001c     14  addu $v0, $v0, $a0\n"
+    ));
+    assert!(stdout.contains("(debug) $a0 = 0x00000009 (9)\n"));
+    assert!(stdout
+        .contains("(debug) 00400020     15  addiu $a0, $a0, -1\n(debug) $a0 = 0x0000000a (10)\n"));
+    assert!(stdout.contains("(debug) Breakpoint at 0x00400028.\n00400028     17  jal func\n"));
+    assert!(stdout.contains("(debug) 0040002c     18  j end\n(debug) $v1 = 0x0000004d (77)\n"));
+    assert!(stdout.contains("(debug) Program finished after 41 steps.\n"));
+}
+
+#[test]
+fn test_main_debug_watch() {
+    use fixtures::INPUT_CASE_14;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_14.as_bytes()).unwrap();
+
+    let assert = assert_cmd::Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("debug")
+        .arg(input_file.path())
+        .write_stdin("watch count\ncontinue\ncontinue\nreverse-step\nx count\n")
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.contains("(debug) Watchpoint 0x10000000: 0x00000000 -> 0x00000003.\n"));
+    assert!(stdout.contains("(debug) Watchpoint 0x10000000: 0x00000003 -> 0x00000004.\n"));
+    assert!(stdout
+        .contains("(debug) 00400010      9  sw $t1, 0($t0)\n(debug) 0x10000000: 0x00000003 (3)\n"));
+}
+
+#[test]
+fn test_main_run_gdb() {
+    use fixtures::INPUT_CASE_10;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpStream;
+    use std::process::Stdio;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_10.as_bytes()).unwrap();
+
+    let mut child = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["run", "--gdb", "0"])
+        .arg(input_file.path())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut stderr = BufReader::new(child.stderr.take().unwrap());
+    let mut line = String::new();
+    stderr.read_line(&mut line).unwrap();
+    let address = line
+        .trim()
+        .strip_prefix("Waiting for GDB connection on ")
+        .unwrap()
+        .trim_end_matches('.')
+        .to_string();
+
+    let mut stream = TcpStream::connect(address).unwrap();
+    let mut send = |data: &str| -> String {
+        let checksum = data.bytes().fold(0u8, |sum, byte| sum.wrapping_add(byte));
+        write!(stream, "${}#{:02x}", data, checksum).unwrap();
+
+        let mut reply: Vec<u8> = vec![];
+        let mut byte = [0u8; 1];
+        while !(reply.len() > 3 && reply[reply.len() - 3] == b'#') {
+            stream.read_exact(&mut byte).unwrap();
+            if !(reply.is_empty() && byte[0] == b'+') {
+                reply.push(byte[0]);
+            }
+        }
+        stream.write_all(b"+").unwrap();
+        let reply = String::from_utf8(reply).unwrap();
+        reply[1..reply.len() - 3].to_string()
+    };
+
+    assert_eq!(send("?"), "S05");
+    assert_eq!(send("p25"), "00400000");
+    assert_eq!(send("p1d"), "7ffffffc");
+    assert_eq!(send("m10000000,8"), "00000005fffffff9");
+    assert_eq!(send("Z0,40001c,4"), "OK");
+    assert_eq!(send("c"), "S05");
+    assert_eq!(send("p4"), "0000000a");
+    assert_eq!(send("P4=00000002"), "OK");
+    assert_eq!(send("c"), "S05");
+    assert_eq!(send("p4"), "00000001");
+    assert_eq!(send("z0,40001c,4"), "OK");
+    assert_eq!(send("s"), "S05");
+    assert_eq!(send("p25"), "00400020");
+    assert_eq!(send("M10000000,4:0000002a"), "OK");
+    assert_eq!(send("m10000000,4"), "0000002a");
+    assert_eq!(send("c"), "W00");
+
+    assert!(child.wait().unwrap().success());
+}
+
+#[test]
+fn test_main_comments_and_blank_lines() {
+    use fixtures::{INPUT_CASE_15, INPUT_CASE_28};
+
+    let assemble = |input: &str| {
+        let mut input_file = NamedTempFile::new().unwrap();
+        input_file.write_all(input.as_bytes()).unwrap();
+
+        let mut output_file = NamedTempFile::new().unwrap();
+
+        Command::cargo_bin(BIN_NAME)
+            .unwrap()
+            .args([input_file.path(), output_file.path()])
+            .assert()
+            .success();
+
+        let mut actual = String::new();
+        output_file.read_to_string(&mut actual).unwrap();
+        actual
+    };
+
+    assert_eq!(assemble(INPUT_CASE_28), assemble(INPUT_CASE_15));
+}
+
+#[test]
+fn test_main_run_pipeline() {
+    use fixtures::{INPUT_CASE_15, OUTPUT_CASE_15_PIPELINE};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_15.as_bytes()).unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["run", "--pipeline"])
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.starts_with(OUTPUT_CASE_15_PIPELINE));
+    assert!(stderr.contains("Program finished after 11 steps."));
+}
+
+#[test]
+fn test_main_run_pipeline_same_sources() {
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file
+        .write_all(b"\t.text\nmain:\n\taddiu\t$t0, $zero, 1\n\taddu\t$t1, $t0, $t0\n")
+        .unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["run", "--pipeline"])
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr
+        .contains("addiu $t0, $zero, 1                             forward $t0 EX/MEM -> EX\n"));
+    assert!(stderr.contains("Instructions: 2\nCycles: 6\nCPI: 3.00\n"));
+}
+
+#[test]
+fn test_main_run_pipeline_strategies() {
+    use fixtures::INPUT_CASE_15;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_15.as_bytes()).unwrap();
+
+    let cases = [
+        (
+            vec!["--branch-strategy", "stall"],
+            "Cycles: 20\nCPI: 1.82\nStalls: 2 data, 1 load-use, 4 control\n",
+        ),
+        (
+            vec!["--delay-slots"],
+            "Instructions: 13\nCycles: 20\nCPI: 1.54\nStalls: 2 data, 1 load-use, 0 control\n",
+        ),
+        (
+            vec!["--forwarding", "off", "--branch-strategy", "stall"],
+            "Cycles: 29\nCPI: 2.64\nStalls: 12 data, 0 load-use, 6 control\n",
+        ),
+    ];
+    cases.iter().for_each(|(args, expected)| {
+        let assert = Command::cargo_bin(BIN_NAME)
+            .unwrap()
+            .args(["run", "--pipeline"])
+            .args(args)
+            .arg(input_file.path())
+            .assert()
+            .success();
+
+        let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+        assert!(stderr.contains(expected));
+    });
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["run", "--pipeline", "--branch-strategy", "delayed"])
+        .arg(input_file.path())
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("The delayed branch strategy requires --delay-slots."));
+}
+
+#[test]
+fn test_main_hazards() {
+    use fixtures::INPUT_CASE_16;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_16.as_bytes()).unwrap();
+
+    let output_file = NamedTempFile::new().unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("--hazards")
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert!(stdout.contains("00400004     6  00400008     7  $t1              1  MEM/WB -> EX  1 load-use                     2  lw $t1, 0($t0) -> add $t2, $t1, $t1\n"));
+    assert!(stdout.contains("00400008     7  0040000c     8  $t2              1  EX/MEM -> EX  0                              2  add $t2, $t1, $t1 -> sub $t3, $t2, $t1\n"));
+    assert!(stdout.contains("00400014    11  00400018    12  $a0              1  EX/MEM -> ID  1                              2  addiu $a0, $a0, -1 -> bne $a0, $zero, loop\n"));
+    assert!(stdout.contains("6 dependencies, 6 forwarded, 1 load-use stalls, 2 stall cycles with forwarding, 10 without forwarding\n"));
+}
+
+#[test]
+fn test_main_insert_nops() {
+    use fixtures::INPUT_CASE_16;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_16.as_bytes()).unwrap();
+
+    let output_file = NamedTempFile::new().unwrap();
+    let mut report_file = NamedTempFile::new().unwrap();
+    let mut listing_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("--insert-nops")
+        .arg(format!("--hazards={}", report_file.path().display()))
+        .arg("-l")
+        .arg(listing_file.path())
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    report_file.read_to_string(&mut actual).unwrap();
+
+    assert!(actual.contains("00400034    11  00400040    12  $a0              3  MEM/WB -> ID  0                              0  addiu $a0, $a0, -1 -> bne $a0, $zero, loop\n"));
+    assert!(actual.ends_with("5 dependencies, 1 forwarded, 0 load-use stalls, 0 stall cycles with forwarding, 0 without forwarding\n"));
+
+    let mut actual = String::new();
+    listing_file.read_to_string(&mut actual).unwrap();
+
+    assert!(actual.contains("0040002c               10  loop:\n0040002c  00000000     11  nop"));
+    assert!(actual.contains("00400040  1480fffa     12  bne $a0, $zero, loop\n"));
+}
+
+#[test]
+fn test_main_delay_slots() {
+    use fixtures::{INPUT_CASE_17, OUTPUT_CASE_17_DISASSEMBLY};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_17.as_bytes()).unwrap();
+
+    let output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("--delay-slots")
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("disasm")
+        .arg(output_file.path())
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert_eq!(stdout, OUTPUT_CASE_17_DISASSEMBLY);
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["run", "--delay-slots"])
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.starts_with("Program exited with code 0 after 23 steps.\n"));
+    assert!(stderr.contains("$a0   = 0x0000000f"));
+    assert!(stderr.contains("$v0   = 0x0000000a"));
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["run", "--delay-slots", "--pipeline"])
+        .args(["--branch-strategy", "stall"])
+        .arg(input_file.path())
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("--delay-slots requires the delayed branch strategy."));
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr
+        .contains(":14: .set reorder and .set noreorder have no effect without --delay-slots.\n"));
+}
+
+#[test]
+fn test_main_split_caches() {
+    use fixtures::INPUT_CASE_18;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_18.as_bytes()).unwrap();
+
+    let mut trace_file = NamedTempFile::new().unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .args(["--icache", "size=32,block=16"])
+        .args(["--dcache", "size=32,block=8,ways=2,write=through"])
+        .arg(format!("--cache-trace={}", trace_file.path().display()))
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.starts_with(
+        "L1I cache: 32 bytes, 16-byte blocks, direct-mapped, LRU, write-back
+Accesses: 51 (51 reads, 0 writes)
+Hits: 34, misses: 17 (17 read, 0 write)
+Hit rate: 66.67%, miss rate: 33.33%
+Memory writes: 0
+L1D cache: 32 bytes, 8-byte blocks, 2-way, LRU, write-through
+Accesses: 16 (8 reads, 8 writes)
+Hits: 12, misses: 4 (4 read, 0 write)
+Hit rate: 75.00%, miss rate: 25.00%
+Memory writes: 8
+Program finished after 51 steps.
+"
+    ));
+
+    let mut actual = String::new();
+    trace_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual.lines().count(), 67);
+    assert!(actual.starts_with("L1I  fetch  0x00400000  set 0     miss\n"));
+    assert!(actual.contains("L1D  load   0x10000000  set 0     miss\n"));
+    assert!(actual.contains("L1I  fetch  0x00400020  set 0     miss  evict 0x00400000\n"));
+}
+
+#[test]
+fn test_main_unified_cache() {
+    use fixtures::INPUT_CASE_18;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_18.as_bytes()).unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .args(["--cache", "size=64,block=16,ways=full,replacement=fifo"])
+        .arg("--cache-trace")
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("L1   store  0x10000000  set 0     hit\n"));
+    assert!(stderr.contains(
+        "L1 cache: 64 bytes, 16-byte blocks, fully associative, FIFO, write-back
+Accesses: 67 (59 reads, 8 writes)
+Hits: 61, misses: 6 (6 read, 0 write)
+Hit rate: 91.04%, miss rate: 8.96%
+Memory writes: 1
+"
+    ));
+}
+
+#[test]
+fn test_main_mmio_devices() {
+    use fixtures::INPUT_CASE_19;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_19.as_bytes()).unwrap();
+
+    let display_file = tempfile::Builder::new().suffix(".ppm").tempfile().unwrap();
+
+    let assert = assert_cmd::Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg("--mmio")
+        .arg("--display")
+        .arg(display_file.path())
+        .args(["--display-size", "4x2"])
+        .arg(input_file.path())
+        .write_stdin("Hi!")
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).into_owned();
+    assert_eq!(stdout, "Hi!");
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("$s2   = 0x00000003  $s3   = 0x00000003"));
+
+    let mut expected = b"P6\n4 2\n255\n".to_vec();
+    expected.extend([0, 0, b'H', 0, 0, b'i', 0, 0, b'!']);
+    expected.extend([0; 15]);
+    assert_eq!(std::fs::read(display_file.path()).unwrap(), expected);
+}
+
+#[test]
+fn test_main_display_png() {
+    use fixtures::INPUT_CASE_19;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_19.as_bytes()).unwrap();
+
+    let display_file = tempfile::Builder::new().suffix(".png").tempfile().unwrap();
+
+    assert_cmd::Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg("--mmio")
+        .arg("--display")
+        .arg(display_file.path())
+        .args(["--display-size", "4x2"])
+        .arg(input_file.path())
+        .write_stdin("A")
+        .assert()
+        .success();
+
+    let png = std::fs::read(display_file.path()).unwrap();
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x04\0\0\0\x02\x08\x02"));
+    assert!(png.windows(4).any(|window| window == b"IDAT"));
+    assert!(png.windows(4).any(|window| window == [0, 0, 0, b'A']));
+    assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+}
+
+#[test]
+fn test_main_display_heap() {
+    use fixtures::INPUT_CASE_22;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_22.as_bytes()).unwrap();
+
+    let display_file = tempfile::Builder::new().suffix(".ppm").tempfile().unwrap();
+
+    let assert = assert_cmd::Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg("--display")
+        .arg(display_file.path())
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("$s0   = 0x10042000"));
+}
+
+#[test]
+fn test_main_kernel_exception_handler() {
+    use fixtures::INPUT_CASE_20;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_20.as_bytes()).unwrap();
+
+    let assert = assert_cmd::Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg("--mmio")
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("Program finished after 46 steps."));
+    assert!(stderr
+        .contains("status = 0x0000ff11  cause = 0x00000010  epc = 0x00400028  vaddr = 0x00000001"));
+    assert!(stderr.contains("$s0   = 0x00000002  $s1   = 0x00000001"));
+
+    let output_file = NamedTempFile::new().unwrap();
+    assert_cmd::Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg(input_file.path())
+        .arg(output_file.path())
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_main_elf_kernel_sections() {
+    use fixtures::INPUT_CASE_20;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_20.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "elf-exec"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = vec![];
+    output_file.read_to_end(&mut actual).unwrap();
+
+    let half = |offset: usize| u16::from_be_bytes([actual[offset], actual[offset + 1]]);
+    let word = |offset: usize| {
+        u32::from_be_bytes([
+            actual[offset],
+            actual[offset + 1],
+            actual[offset + 2],
+            actual[offset + 3],
+        ])
+    };
+    let program_header = |index: usize| word(0x1c) as usize + index * 32;
+
+    assert_eq!(half(0x2c), 3);
+
+    let kernel_text_segment = program_header(1);
+    assert_eq!(word(kernel_text_segment + 0x08), 0x8000_0180);
+    assert_eq!(word(kernel_text_segment + 0x10), 19 * 4);
+    assert_eq!(word(kernel_text_segment + 0x18), 0x5);
+    assert_eq!(word(kernel_text_segment + 0x04) % 0x1000, 0x180);
+    assert_eq!(word(word(kernel_text_segment + 0x04) as usize), 0x401a_6800);
+
+    let kernel_data_segment = program_header(2);
+    assert_eq!(word(kernel_data_segment + 0x08), 0x9000_0000);
+    assert_eq!(word(kernel_data_segment + 0x10), 4);
+    assert_eq!(word(kernel_data_segment + 0x18), 0x6);
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "bin"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(
+        stderr.contains("Kernel sections can only be used with run, debug and the elf formats.")
+    );
+}
+
+#[test]
+fn test_main_floating_point() {
+    use fixtures::{INPUT_CASE_21, OUTPUT_CASE_21_DISASM};
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_21.as_bytes()).unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("run")
+        .arg(input_file.path())
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("Program finished after 34 steps."));
+    assert!(stderr.contains(
+        "$s0   = 0x00000001  $s1   = 0x00000006  $s2   = 0x00000009  $s3   = 0x40490fdb"
+    ));
+    assert!(stderr.contains(
+        "$s4   = 0x400921fb  $s5   = 0x54442d18  $s6   = 0x00000000  $s7   = 0x00000000"
+    ));
+    assert!(stderr.contains(
+        "$f8   = 0x40400000  $f9   = 0x00000000  $f10  = 0x40c00000  $f11  = 0x00000000"
+    ));
+    assert!(stderr.contains("fcc   = 0"));
+
+    let output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut disassembly_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .arg("disasm")
+        .args([output_file.path(), disassembly_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = String::new();
+    disassembly_file.read_to_string(&mut actual).unwrap();
+
+    assert_eq!(actual, OUTPUT_CASE_21_DISASM);
+}
+
+#[test]
+fn test_main_register_class_mismatch() {
+    use fixtures::{INPUT_CASE_24, INPUT_CASE_25};
+
+    [
+        (INPUT_CASE_24, "Expected a floating-point register: $t0"),
+        (INPUT_CASE_25, "Expected a general-purpose register: $f0"),
+    ]
+    .iter()
+    .for_each(|(input, message)| {
+        let mut input_file = NamedTempFile::new().unwrap();
+        input_file.write_all(input.as_bytes()).unwrap();
+
+        let output_file = NamedTempFile::new().unwrap();
+
+        let assert = Command::cargo_bin(BIN_NAME)
+            .unwrap()
+            .args([input_file.path(), output_file.path()])
+            .assert()
+            .failure();
+
+        let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+        assert!(stderr.contains(message));
+    });
+}
+
+#[test]
+fn test_main_double_endianness() {
+    use fixtures::INPUT_CASE_23;
+
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file.write_all(INPUT_CASE_23.as_bytes()).unwrap();
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "bin"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = vec![];
+    output_file.read_to_end(&mut actual).unwrap();
+    assert_eq!(actual[actual.len() - 8..], [0x3f, 0xf0, 0, 0, 0, 0, 0, 0]);
+
+    let mut output_file = NamedTempFile::new().unwrap();
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "bin", "-EL"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+
+    let mut actual = vec![];
+    output_file.read_to_end(&mut actual).unwrap();
+    assert_eq!(actual[actual.len() - 8..], [0, 0, 0, 0, 0, 0, 0xf0, 0x3f]);
+}
+
+#[test]
+fn test_main_undefined_symbol() {
+    let mut input_file = NamedTempFile::new().unwrap();
+    input_file
+        .write_all(b"\t.extern\tfoo\n\t.text\nmain:\n\tbeq\t$t0, $t1, foo\n\tjal\tfoo\n")
+        .unwrap();
+
+    let output_file = NamedTempFile::new().unwrap();
+
+    let assert = Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).into_owned();
+    assert!(stderr.contains("Undefined symbol: foo"));
+
+    Command::cargo_bin(BIN_NAME)
+        .unwrap()
+        .args(["--format", "elf"])
+        .args([input_file.path(), output_file.path()])
+        .assert()
+        .success();
+}
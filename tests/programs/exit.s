@@ -0,0 +1,9 @@
+	.data
+status:	.word	42
+	.text
+	.globl	__start
+__start:
+	la	$8, status
+	lw	$4, 0($8)
+	addiu	$2, $0, 4001
+	syscall
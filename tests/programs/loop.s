@@ -0,0 +1,15 @@
+	.text
+	.globl	__start
+__start:
+	addiu	$16, $0, 0
+	addiu	$17, $0, 5
+loop:
+	jal	add_seven
+	addiu	$17, $17, -1
+	bne	$17, $0, loop
+	addu	$4, $16, $0
+	addiu	$2, $0, 4001
+	syscall
+add_seven:
+	addiu	$16, $16, 7
+	jr	$31